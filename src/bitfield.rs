@@ -0,0 +1,261 @@
+//! Bit level packing of several bounded values into one integer.
+//!
+//! Each field occupies as many bits as `Max - Min` of its bound's `Interval` needs,
+//! and is stored as an offset from the minimum.
+//! The first field of a tuple takes the most significant bits.
+//!
+//! # Example
+//! ```
+//! use boundnum::{bitfield::Fields, expr::*, typenum::consts::*, Bounded};
+//!
+//! type Version = Bounded<u8, RangeInclusive<U4, U6>>;
+//! type Length = Bounded<u16, Range<U0, U1024>>;
+//!
+//! let header = (Version::new::<U6>(), Length::new::<U100>());
+//! let word: u16 = header.pack();
+//! assert_eq!((2 << 10) | 100, word);
+//! assert_eq!(Some(header), Fields::unpack(word));
+//! ```
+//!
+//! Fields which do not fit into the integer are rejected at compile time.
+//! ```compile_fail
+//! use boundnum::{bitfield::Fields, expr::*, typenum::consts::*, Bounded};
+//!
+//! let fields = (Bounded::<u8, Le<Arg, U255>>::new::<U1>(), Bounded::<u8, Le<Arg, U1>>::new::<U1>());
+//! let word: u8 = fields.pack();
+//! ```
+
+use crate::{
+    packed::{Offset, Packable},
+    typenum::{IsLessOrEqual, Sum, True, Unsigned, U128, U16, U32, U64, U8},
+    Bounded,
+};
+use core::{marker::PhantomData, ops::Add};
+
+/// Returns the mask of the lowest `bits` bits.
+fn mask(bits: u32) -> u128 {
+    u128::MAX.checked_shr(128 - bits).unwrap_or(0)
+}
+
+/// A value occupying a fixed number of bits.
+pub trait Field: Sized {
+    /// The number of bits.
+    type Bits: Unsigned;
+    fn into_bits(self) -> u128;
+    /// Returns `None` if `bits` is not a valid value.
+    fn from_bits(bits: u128) -> Option<Self>;
+}
+
+impl<T, B> Field for Bounded<T, B>
+where
+    T: Offset,
+    B: Packable<T>,
+{
+    type Bits = B::Bits;
+
+    #[inline]
+    fn into_bits(self) -> u128 {
+        self.value().offset_from(B::MIN) as u128
+    }
+
+    fn from_bits(bits: u128) -> Option<Self> {
+        if bits > B::SPAN as u128 {
+            return None;
+        }
+        let value = T::from_offset(B::MIN, bits as u64);
+        if B::contains(&value) {
+            Some(Bounded {
+                value,
+                bound: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Unsigned integers holding packed fields.
+pub trait Word: Copy {
+    /// The number of bits.
+    type Bits: Unsigned;
+    fn from_bits(bits: u128) -> Self;
+    fn into_bits(self) -> u128;
+}
+
+macro_rules! impl_word {
+    ( $Word:ty, $Bits:ty ) => {
+        impl Word for $Word {
+            type Bits = $Bits;
+
+            #[inline]
+            fn from_bits(bits: u128) -> Self {
+                bits as Self
+            }
+
+            #[inline]
+            fn into_bits(self) -> u128 {
+                self as u128
+            }
+        }
+    };
+}
+
+impl_word!(u8, U8);
+impl_word!(u16, U16);
+impl_word!(u32, U32);
+impl_word!(u64, U64);
+impl_word!(u128, U128);
+
+/// A tuple of `Field`s laid out from the most significant bits.
+pub trait FieldList: Sized {
+    /// The total number of bits.
+    type Bits: Unsigned;
+    fn into_bits(self) -> u128;
+    fn from_bits(bits: u128) -> Option<Self>;
+}
+
+macro_rules! impl_field_list {
+    ( $Head:ident ) => {
+        impl<$Head: Field> FieldList for ($Head,) {
+            type Bits = <$Head as Field>::Bits;
+
+            #[inline]
+            fn into_bits(self) -> u128 {
+                self.0.into_bits()
+            }
+
+            #[inline]
+            fn from_bits(bits: u128) -> Option<Self> {
+                Some(($Head::from_bits(bits)?,))
+            }
+        }
+    };
+    ( $Head:ident $(, $Tail:ident)+ ) => {
+        impl<$Head $(, $Tail)*> FieldList for ($Head, $($Tail,)*)
+        where
+            $Head: Field,
+            ($($Tail,)*): FieldList,
+            <$Head as Field>::Bits: Add<<($($Tail,)*) as FieldList>::Bits>,
+            Sum<<$Head as Field>::Bits, <($($Tail,)*) as FieldList>::Bits>: Unsigned,
+        {
+            type Bits = Sum<<$Head as Field>::Bits, <($($Tail,)*) as FieldList>::Bits>;
+
+            #[allow(non_snake_case)]
+            fn into_bits(self) -> u128 {
+                let ($Head, $($Tail,)*) = self;
+                let tail_bits = <($($Tail,)*) as FieldList>::Bits::U32;
+                $Head.into_bits().checked_shl(tail_bits).unwrap_or(0)
+                    | ($($Tail,)*).into_bits()
+            }
+
+            #[allow(non_snake_case)]
+            fn from_bits(bits: u128) -> Option<Self> {
+                let tail_bits = <($($Tail,)*) as FieldList>::Bits::U32;
+                let $Head = $Head::from_bits(
+                    bits.checked_shr(tail_bits).unwrap_or(0) & mask(<$Head as Field>::Bits::U32),
+                )?;
+                let ($($Tail,)*) = <($($Tail,)*)>::from_bits(bits & mask(tail_bits))?;
+                Some(($Head, $($Tail,)*))
+            }
+        }
+
+        impl_field_list!($($Tail),+);
+    };
+}
+
+impl_field_list!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Packs a tuple of `Field`s into the `Word` `W`.
+///
+/// Implemented only if the fields fit into `W`.
+pub trait Fields<W>: Sized {
+    fn pack(self) -> W;
+    /// Returns `None` if any field is invalid or unused bits are set.
+    fn unpack(word: W) -> Option<Self>;
+}
+
+impl<W, L> Fields<W> for L
+where
+    W: Word,
+    L: FieldList,
+    L::Bits: IsLessOrEqual<W::Bits, Output = True>,
+{
+    #[inline]
+    fn pack(self) -> W {
+        W::from_bits(FieldList::into_bits(self))
+    }
+
+    fn unpack(word: W) -> Option<Self> {
+        let bits = word.into_bits();
+        if bits & !mask(L::Bits::U32) != 0 {
+            return None;
+        }
+        FieldList::from_bits(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expr::{Arg, Eq, Le, Range, RangeInclusive},
+        typenum::consts::*,
+        Boundable,
+    };
+    use impls::impls;
+
+    type Flag = Bounded<u8, Le<Arg, U1>>;
+    type Signed = Bounded<i8, Range<N8, P8>>;
+    type Fixed = Bounded<u32, Eq<Arg, U7>>;
+
+    #[test]
+    fn bits() {
+        assert_eq!(1, <Flag as Field>::Bits::USIZE);
+        assert_eq!(4, <Signed as Field>::Bits::USIZE);
+        assert_eq!(0, <Fixed as Field>::Bits::USIZE);
+        assert_eq!(5, <(Flag, Signed, Fixed) as FieldList>::Bits::USIZE);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for flag in 0..=1 {
+            for signed in -8..8 {
+                let fields: (Flag, Signed, Fixed) = (
+                    flag.bound().unwrap(),
+                    signed.bound().unwrap(),
+                    Fixed::new::<U7>(),
+                );
+                let word: u8 = fields.pack();
+                assert_eq!((flag << 4) | (signed + 8) as u8, word);
+                assert_eq!(Some(fields), Fields::unpack(word));
+            }
+        }
+    }
+
+    #[test]
+    fn unpack_invalid() {
+        type Small = Bounded<u8, RangeInclusive<U1, U5>>;
+        assert_eq!(None, <(Small,) as Fields<u8>>::unpack(5));
+        assert_eq!(None, <(Small, Flag) as Fields<u8>>::unpack(0b1000_0000));
+        assert_eq!(
+            Some((Small::new::<U5>(), Flag::new::<U1>())),
+            Fields::unpack(0b1001u8)
+        );
+    }
+
+    #[test]
+    fn full_width() {
+        type Full = Bounded<u64, Le<Arg, <u64 as crate::value::TypeBound>::Max>>;
+        let fields: (Full, Full) = (u64::MAX.bound().unwrap(), 1.bound().unwrap());
+        let word: u128 = fields.pack();
+        assert_eq!(((u64::MAX as u128) << 64) | 1, word);
+        assert_eq!(Some(fields), Fields::unpack(word));
+    }
+
+    #[test]
+    fn must_fit() {
+        assert!(impls!((Signed, Signed): Fields<u8>));
+        assert!(impls!((Signed, Signed, Flag): !Fields<u8>));
+        assert!(impls!((Signed, Signed, Flag): Fields<u16>));
+    }
+}
@@ -3,3 +3,5 @@ mod convert;
 mod fmt;
 mod misc;
 mod ops;
+#[cfg(feature = "serde")]
+mod serde;
@@ -1,6 +1,18 @@
 //! refers [core::convert](https://doc.rust-lang.org/src/core/convert/num.rs.html)
-use crate::{AsBound, Bounded};
-use core::convert::{TryFrom, TryInto};
+use crate::{
+    expr::{Arg, ConstRange, Contains, Ne, Range, Tautology},
+    value::{ToI128, TypeBound},
+    AsBound, Bounded,
+};
+use core::{
+    convert::{TryFrom, TryInto},
+    marker::PhantomData,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+};
+use typenum::{U0, Z0};
 
 macro_rules! impl_from_bounded_for_internal_value {
     ($Internal: ty) => {
@@ -31,6 +43,21 @@ impl_from_bounded_for_internal_value! { u32 }
 impl_from_bounded_for_internal_value! { u64 }
 impl_from_bounded_for_internal_value! { usize }
 
+/// Bounds satisfied by every value of `T` never reject a value.
+impl<T, B> From<T> for Bounded<T, B>
+where
+    T: TypeBound,
+    B: AsBound<T> + Tautology<T>,
+{
+    #[inline]
+    fn from(value: T) -> Self {
+        Bounded {
+            value,
+            bound: PhantomData,
+        }
+    }
+}
+
 macro_rules! impl_from_bounded_for_bounded {
     ($Small: ty, $Large: ty, $doc: expr) => {
         #[doc = $doc]
@@ -281,3 +308,215 @@ mod ptr_try_from_impls {
     rev!(impl_try_from, isize, i32, i64);
     rev!(impl_try_from, isize, i128);
 }
+
+macro_rules! impl_from_nonzero {
+    ($Internal: ty, $NonZero: ty, $Zero: ty, $doc_from: expr, $doc_into: expr) => {
+        #[doc = $doc_from]
+        impl<B> From<Bounded<$Internal, B>> for $NonZero
+        where
+            B: AsBound<$Internal> + Contains<$Zero, Output = typenum::False>,
+        {
+            #[inline]
+            fn from(bounded: Bounded<$Internal, B>) -> Self {
+                match <$NonZero>::new(bounded.value()) {
+                    Some(nonzero) => nonzero,
+                    None => unreachable!("the bound excludes zero"),
+                }
+            }
+        }
+
+        #[doc = $doc_into]
+        impl From<$NonZero> for Bounded<$Internal, Ne<Arg, $Zero>> {
+            #[inline]
+            fn from(nonzero: $NonZero) -> Self {
+                Bounded {
+                    value: nonzero.get(),
+                    bound: Default::default(),
+                }
+            }
+        }
+    };
+    ($Internal: ty, $NonZero: ty, $Zero: ty) => {
+        impl_from_nonzero!($Internal,
+                   $NonZero,
+                   $Zero,
+                   concat!("Converts `Bounded<",
+                           stringify!($Internal),
+                           ", B>` to `",
+                           stringify!($NonZero),
+                           "` losslessly if `B` excludes zero."),
+                   concat!("Converts `",
+                           stringify!($NonZero),
+                           "` to `Bounded<",
+                           stringify!($Internal),
+                           ", Ne<Arg, ",
+                           stringify!($Zero),
+                           ">>` losslessly."));
+    }
+}
+
+impl_from_nonzero! { i8, NonZeroI8, Z0 }
+impl_from_nonzero! { i16, NonZeroI16, Z0 }
+impl_from_nonzero! { i32, NonZeroI32, Z0 }
+impl_from_nonzero! { i64, NonZeroI64, Z0 }
+impl_from_nonzero! { i128, NonZeroI128, Z0 }
+impl_from_nonzero! { isize, NonZeroIsize, Z0 }
+impl_from_nonzero! { u8, NonZeroU8, U0 }
+impl_from_nonzero! { u16, NonZeroU16, U0 }
+impl_from_nonzero! { u32, NonZeroU32, U0 }
+impl_from_nonzero! { u64, NonZeroU64, U0 }
+impl_from_nonzero! { u128, NonZeroU128, U0 }
+impl_from_nonzero! { usize, NonZeroUsize, U0 }
+
+/// Fails to compile if `Start..End` differs from `START..END`.
+struct AssertSameRange<Start, End, const START: i64, const END: i64>(PhantomData<(Start, End)>);
+
+impl<Start, End, const START: i64, const END: i64> AssertSameRange<Start, End, START, END>
+where
+    Start: ToI128,
+    End: ToI128,
+{
+    const ASSERT: () = assert!(
+        Start::I128 == START as i128 && End::I128 == END as i128,
+        "`Range<Start, End>` differs from `ConstRange<START, END>`"
+    );
+}
+
+/// Converts `Bounded<T, Range<Start, End>>` to `Bounded<T, ConstRange<START, END>>`.
+///
+/// Fails to compile if the ranges differ.
+impl<T, Start, End, const START: i64, const END: i64> From<Bounded<T, Range<Start, End>>>
+    for Bounded<T, ConstRange<START, END>>
+where
+    Range<Start, End>: AsBound<T>,
+    ConstRange<START, END>: AsBound<T>,
+    Start: ToI128,
+    End: ToI128,
+{
+    #[inline]
+    fn from(bounded: Bounded<T, Range<Start, End>>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertSameRange::<Start, End, START, END>::ASSERT;
+        Bounded {
+            value: bounded.value(),
+            bound: Default::default(),
+        }
+    }
+}
+
+/// Converts `Bounded<T, ConstRange<START, END>>` to `Bounded<T, Range<Start, End>>`.
+///
+/// Fails to compile if the ranges differ.
+impl<T, Start, End, const START: i64, const END: i64> From<Bounded<T, ConstRange<START, END>>>
+    for Bounded<T, Range<Start, End>>
+where
+    Range<Start, End>: AsBound<T>,
+    ConstRange<START, END>: AsBound<T>,
+    Start: ToI128,
+    End: ToI128,
+{
+    #[inline]
+    fn from(bounded: Bounded<T, ConstRange<START, END>>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertSameRange::<Start, End, START, END>::ASSERT;
+        Bounded {
+            value: bounded.value(),
+            bound: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expr::{BitAnd, BitOr, Eq, Ge, Gt, Le, Lt, Range, RangeInclusive, Rem},
+        Boundable,
+    };
+    use typenum::consts::*;
+
+    #[test]
+    fn const_range() {
+        let bounded: Bounded<i32, ConstRange<-3, 5>> = 4.bound().unwrap();
+        assert!(Boundable::<ConstRange<-3, 5>>::bound(5i32).is_none());
+        assert!(Boundable::<ConstRange<-3, 5>>::bound(-4i32).is_none());
+
+        let typed: Bounded<i32, Range<N3, P5>> = bounded.into();
+        assert_eq!(4, typed.value());
+        let back: Bounded<i32, ConstRange<-3, 5>> = Bounded::<i32, Range<N3, P5>>::new::<N3>().into();
+        assert_eq!(-3, back.value());
+    }
+
+    #[test]
+    fn const_range_limits() {
+        assert!(Boundable::<ConstRange<0, { i64::MAX }>>::bound(u64::MAX).is_none());
+        assert!(Boundable::<ConstRange<{ i64::MIN }, 0>>::bound(i64::MIN).is_some());
+    }
+
+    #[test]
+    fn nonzero_roundtrip() {
+        let nonzero = NonZeroU32::new(42).unwrap();
+        let bounded: Bounded<u32, Ne<Arg, U0>> = nonzero.into();
+        assert_eq!(42, bounded.value());
+        assert_eq!(nonzero, NonZeroU32::from(bounded));
+
+        let wide = NonZeroI128::new(i128::MIN).unwrap();
+        let bounded: Bounded<i128, Ne<Arg, Z0>> = wide.into();
+        assert_eq!(wide, NonZeroI128::from(bounded));
+        let wide = NonZeroU128::new(u128::MAX).unwrap();
+        let bounded: Bounded<u128, Ne<Arg, U0>> = wide.into();
+        assert_eq!(wide, NonZeroU128::from(bounded));
+        assert!(Boundable::<Ne<Arg, Z0>>::bound(0i128).is_none());
+        assert!(Boundable::<Gt<Arg, N1>>::bound(0u128).is_some());
+    }
+
+    #[test]
+    fn nonzero_from_excluding_bound() {
+        let range = Bounded::<i8, Range<P1, P10>>::new::<P3>();
+        assert_eq!(3, NonZeroI8::from(range).get());
+
+        let positive: Bounded<u16, Gt<Arg, U0>> = 7.bound().unwrap();
+        assert_eq!(7, NonZeroU16::from(positive).get());
+    }
+
+    #[test]
+    fn nonzero_requires_zero_excluded() {
+        use impls::impls;
+        assert!(impls!(NonZeroU8: From<Bounded<u8, Range<U1, U5>>>));
+        assert!(!impls!(NonZeroU8: From<Bounded<u8, Range<U0, U5>>>));
+        assert!(!impls!(NonZeroI32: From<Bounded<i32, Range<N1, P5>>>));
+    }
+
+    #[test]
+    fn from_tautology() {
+        let bounded: Bounded<u8, Lt<Arg, U256>> = 255.into();
+        assert_eq!(255, bounded.value());
+        assert_eq!(-128, Bounded::<i8, Ge<Arg, N200>>::from(-128).value());
+        assert_eq!(7, Bounded::<u64, Ge<Arg, U0>>::from(7).value());
+        assert_eq!(7, Bounded::<i16, RangeInclusive<N65536, P65536>>::from(7).value());
+    }
+
+    #[test]
+    fn tautology_detection() {
+        use impls::impls;
+        assert!(impls!(Bounded<u8, Le<Arg, U300>>: From<u8>));
+        assert!(impls!(Bounded<i8, BitAnd<Gt<Arg, N129>, Lt<Arg, P128>>>: From<i8>));
+        assert!(!impls!(Bounded<u8, Lt<Arg, U255>>: From<u8>));
+        assert!(!impls!(Bounded<i8, Ge<Arg, N127>>: From<i8>));
+        assert!(!impls!(Bounded<u8, Ne<Arg, U256>>: From<u8>));
+        assert!(!impls!(Bounded<u8, BitOr<Le<Arg, U9>, Ge<Arg, U10>>>: From<u8>));
+        assert!(impls!(Bounded<u8, Eq<Rem<Arg, U1>, U0>>: From<u8>));
+        assert!(!impls!(Bounded<u8, Eq<Rem<Arg, U2>, U0>>: From<u8>));
+    }
+
+    #[test]
+    fn out_of_range_constants() {
+        use impls::impls;
+        crate::check::check_exhaustive::<u8, Lt<Arg, U256>>();
+        crate::check::check_exhaustive::<u8, BitAnd<Ge<Arg, U9>, Le<Arg, U300>>>();
+        crate::check::check_exhaustive::<i8, BitAnd<Gt<Arg, N300>, Lt<Arg, P128>>>();
+        assert!(Boundable::<Ne<Arg, U256>>::bound(255u8).is_some());
+        assert!(!<Eq<Arg, U256> as AsBound<u8>>::contains(&0));
+        assert!(impls!(Eq<Arg, U256>: !crate::expr::NonEmpty<u8>));
+    }
+}
@@ -69,3 +69,4 @@ macro_rules! impl_assign {
 
 mod arith;
 mod bit;
+mod index;
@@ -0,0 +1,59 @@
+use crate::{
+    expr::{AsBound, Interval, MaxOf},
+    typenum::{Const, IsLess, ToUInt, True, U},
+    Bounded, GetBounded,
+};
+use core::ops::{Index, IndexMut};
+
+/// The index never exceeds the upper end of `B`'s `Interval`,
+/// which is proven to be less than `N`.
+impl<T, B, const N: usize> GetBounded<B> for [T; N]
+where
+    B: AsBound<usize> + Interval<usize>,
+    Const<N>: ToUInt,
+    MaxOf<B, usize>: IsLess<U<N>, Output = True>,
+{
+    type Output = T;
+
+    #[inline]
+    fn get_bounded(&self, index: Bounded<usize, B>) -> &Self::Output {
+        let index = index.value();
+        debug_assert!(index < N);
+        // SAFETY: `index` satisfies `B`, so it is at most `MaxOf<B, usize>` and less than `N`.
+        unsafe { self.get_unchecked(index) }
+    }
+
+    #[inline]
+    fn get_bounded_mut(&mut self, index: Bounded<usize, B>) -> &mut Self::Output {
+        let index = index.value();
+        debug_assert!(index < N);
+        // SAFETY: `index` satisfies `B`, so it is at most `MaxOf<B, usize>` and less than `N`.
+        unsafe { self.get_unchecked_mut(index) }
+    }
+}
+
+impl<T, B, const N: usize> Index<Bounded<usize, B>> for [T; N]
+where
+    B: AsBound<usize> + Interval<usize>,
+    Const<N>: ToUInt,
+    MaxOf<B, usize>: IsLess<U<N>, Output = True>,
+{
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Bounded<usize, B>) -> &Self::Output {
+        self.get_bounded(index)
+    }
+}
+
+impl<T, B, const N: usize> IndexMut<Bounded<usize, B>> for [T; N]
+where
+    B: AsBound<usize> + Interval<usize>,
+    Const<N>: ToUInt,
+    MaxOf<B, usize>: IsLess<U<N>, Output = True>,
+{
+    #[inline]
+    fn index_mut(&mut self, index: Bounded<usize, B>) -> &mut Self::Output {
+        self.get_bounded_mut(index)
+    }
+}
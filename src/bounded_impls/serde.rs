@@ -0,0 +1,88 @@
+use crate::{expr::AsBound, Bounded};
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use core::marker::PhantomData;
+
+/// Serializes the value alone, as `T` does.
+impl<T, B> Serialize for Bounded<T, B>
+where
+    T: Serialize,
+    B: AsBound<T>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+/// Deserializes a `T`, and fails if the bound does not hold for it.
+impl<'de, T, B> Deserialize<'de> for Bounded<T, B>
+where
+    T: Deserialize<'de>,
+    B: AsBound<T>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = T::deserialize(deserializer)?;
+        if B::contains(&value) {
+            Ok(Bounded {
+                value,
+                bound: PhantomData,
+            })
+        } else {
+            Err(de::Error::custom("value does not satisfy the bound"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        expr::{Arg, Fst, Le, Lt, Snd},
+        typenum::consts::*,
+        Bounded,
+    };
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    type Digit = Bounded<u8, Lt<Arg, U10>>;
+    type Span = Bounded<(u32, u32), Le<Fst, Snd>>;
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(&Digit::new::<U7>(), &[Token::U8(7)]);
+        assert_tokens(
+            &Span::new::<(U3, U7)>(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::U32(3),
+                Token::U32(7),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn out_of_bound() {
+        assert_de_tokens_error::<Digit>(&[Token::U8(10)], "value does not satisfy the bound");
+        assert_de_tokens_error::<Span>(
+            &[
+                Token::Tuple { len: 2 },
+                Token::U32(7),
+                Token::U32(3),
+                Token::TupleEnd,
+            ],
+            "value does not satisfy the bound",
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned() {
+        use crate::{expr::Len, Boundable};
+        use alloc::string::String;
+        type Name = Bounded<String, Le<Len<Arg>, U8>>;
+        let name: Name = Boundable::bound(String::from("ferris")).unwrap();
+        assert_tokens(&name, &[Token::Str("ferris")]);
+        assert_de_tokens_error::<Name>(
+            &[Token::Str("ferris the crab")],
+            "value does not satisfy the bound",
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! Exhaustive checks of bounds over 8- and 16-bit integers, intended for tests.
+//!
+//! Every check panics with a counterexample on failure.
+//!
+//! `check_contains` and `check_exhaustive` are complete for 8-bit types only.
+//! Every typenum constant is compiled separately, and all 16-bit constants do not compile
+//! within an hour, so 16-bit types are checked with `check_contains_below` and
+//! `check_exhaustive_below`, which walk the constants with a magnitude below `2^Bits`.
+//!
+//! # Example
+//! ```
+//! use boundnum::{check, expr::*, typenum::consts::*, Bounded};
+//!
+//! type Even = BitAnd<Range<N100, P100>, Eq<Rem<Arg, P2>, Z0>>;
+//!
+//! check::check_exhaustive::<i8, Even>();
+//! check::for_all(|even: Bounded<i8, Even>| *even % 2 == 0);
+//! ```
+
+use crate::{
+    codec::{Encodable, Encode},
+    expr::{AsBound, Contains},
+    packed::Offset,
+    typenum::{Bit, NInt, PInt, Sub1, UInt, UTerm, Unsigned, B0, B1, U8, Z0},
+    value::ToI128,
+    Bounded,
+};
+use core::{convert::TryFrom, fmt::Debug, marker::PhantomData, ops::Sub};
+
+/// Primitive integers small enough to be checked exhaustively.
+pub trait Small: Copy + PartialEq + Debug + Offset + TryFrom<i128> {
+    /// Zero as a typenum constant.
+    type Zero: ToI128;
+    /// Calls `f` with every value in ascending order.
+    fn for_each(f: impl FnMut(Self));
+}
+
+macro_rules! impl_small {
+    ( $Int:ty, $Zero:ty ) => {
+        impl Small for $Int {
+            type Zero = $Zero;
+
+            fn for_each(f: impl FnMut(Self)) {
+                (<$Int>::MIN..=<$Int>::MAX).for_each(f)
+            }
+        }
+    };
+}
+
+impl_small!(i8, Z0);
+impl_small!(i16, Z0);
+impl_small!(u8, UTerm);
+impl_small!(u16, UTerm);
+
+/// `Small` types whose typenum constants `check_contains` walks completely.
+pub trait Byte: Small {}
+
+impl Byte for i8 {}
+impl Byte for u8 {}
+
+/// Types whose values can be enumerated exhaustively.
+pub trait Exhaustive: Copy + Debug {
+    /// Calls `f` with every value in ascending order.
+    fn for_each(f: impl FnMut(Self));
+}
+
+impl<T, B> Exhaustive for Bounded<T, B>
+where
+    T: Small,
+    B: AsBound<T> + Copy + Debug,
+{
+    fn for_each(mut f: impl FnMut(Self)) {
+        T::for_each(|value| {
+            if B::contains(&value) {
+                f(Bounded {
+                    value,
+                    bound: PhantomData,
+                })
+            }
+        })
+    }
+}
+
+/// Checks that `f` holds for every value of `K`.
+pub fn for_all<K: Exhaustive>(mut f: impl FnMut(K) -> bool) {
+    K::for_each(|value| {
+        if !f(value) {
+            panic!("counterexample: {:?}", value)
+        }
+    })
+}
+
+/// Checks that `Contains` agrees with `AsBound::contains` for every typenum constant
+/// representable in `T`.
+pub fn check_contains<T, B>()
+where
+    T: Byte,
+    B: AsBound<T> + Contains<T::Zero>,
+    UInt<UTerm, B1>: Walk<T, B, Sub1<U8>>,
+{
+    check_contains_below::<T, B, U8>();
+}
+
+/// Checks `check_contains` for the typenum constants representable in `T`
+/// with a magnitude below `2^Bits`.
+pub fn check_contains_below<T, B, Bits>()
+where
+    T: Small,
+    B: AsBound<T> + Contains<T::Zero>,
+    Bits: Sub<B1>,
+    UInt<UTerm, B1>: Walk<T, B, Sub1<Bits>>,
+{
+    check_constant::<T, B, T::Zero>();
+    <UInt<UTerm, B1> as Walk<T, B, Sub1<Bits>>>::walk();
+}
+
+/// Checks `check_contains`, and that the inferred `Interval` and `Stride` of `B` cover
+/// every value satisfying it, which `Bounded::all` then yields.
+pub fn check_exhaustive<T, B>()
+where
+    T: Byte,
+    B: Encodable<T> + Contains<T::Zero>,
+    UInt<UTerm, B1>: Walk<T, B, Sub1<U8>>,
+{
+    check_exhaustive_below::<T, B, U8>();
+}
+
+/// Checks `check_exhaustive` with `check_contains_below` in place of `check_contains`:
+/// the inferred interval against every value, but `Contains` only below `2^Bits`.
+pub fn check_exhaustive_below<T, B, Bits>()
+where
+    T: Small,
+    B: Encodable<T> + Contains<T::Zero>,
+    Bits: Sub<B1>,
+    UInt<UTerm, B1>: Walk<T, B, Sub1<Bits>>,
+{
+    check_contains_below::<T, B, Bits>();
+
+    let mut all = Bounded::<T, B>::all();
+    T::for_each(|value| {
+        if !B::contains(&value) {
+            return;
+        }
+        let bounded = Bounded::<T, B> {
+            value,
+            bound: PhantomData,
+        };
+        let code = bounded.encode();
+        let decoded = Bounded::<T, B>::decode(code).map(Bounded::value);
+        if code >= B::CARDINALITY || decoded != Some(value) {
+            panic!("{:?} is not covered by the inferred interval", value)
+        }
+        if all.next().map(Bounded::value) != Some(value) {
+            panic!("`Bounded::all` skips {:?}", value)
+        }
+    });
+    if let Some(bounded) = all.next() {
+        panic!("`Bounded::all` yields {:?}", bounded.value())
+    }
+}
+
+fn check_constant<T, B, C>()
+where
+    T: Small,
+    B: AsBound<T> + Contains<C>,
+    C: ToI128,
+{
+    if let Ok(value) = T::try_from(C::I128) {
+        let type_level = <B as Contains<C>>::Output::BOOL;
+        if type_level != B::contains(&value) {
+            panic!(
+                "`Contains` is {} but `ValueExpr::call` is {} for {:?}",
+                type_level, !type_level, value
+            )
+        }
+    }
+}
+
+/// Checks the typenum constants of `T` whose magnitude is `Self` with `Depth` more bits
+/// appended in every way.
+pub trait Walk<T, B, Depth> {
+    fn walk();
+}
+
+impl<T, B, N> Walk<T, B, UTerm> for N
+where
+    N: Constant<T, B>,
+{
+    fn walk() {
+        N::check()
+    }
+}
+
+impl<T, B, N, U, D> Walk<T, B, UInt<U, D>> for N
+where
+    N: Constant<T, B>,
+    U: Unsigned,
+    D: Bit,
+    UInt<U, D>: Sub<B1>,
+    UInt<N, B0>: Walk<T, B, Sub1<UInt<U, D>>>,
+    UInt<N, B1>: Walk<T, B, Sub1<UInt<U, D>>>,
+{
+    fn walk() {
+        N::check();
+        <UInt<N, B0> as Walk<T, B, Sub1<UInt<U, D>>>>::walk();
+        <UInt<N, B1> as Walk<T, B, Sub1<UInt<U, D>>>>::walk();
+    }
+}
+
+/// Checks the typenum constants of `T` whose magnitude is `Self`.
+pub trait Constant<T, B> {
+    fn check();
+}
+
+macro_rules! impl_unsigned_constant {
+    ( $Int:ty ) => {
+        impl<B, N> Constant<$Int, B> for N
+        where
+            B: AsBound<$Int> + Contains<N>,
+            N: ToI128,
+        {
+            fn check() {
+                check_constant::<$Int, B, N>()
+            }
+        }
+    };
+}
+
+impl_unsigned_constant!(u8);
+impl_unsigned_constant!(u16);
+
+macro_rules! impl_signed_constant {
+    ( $Int:ty ) => {
+        impl<B, N> Constant<$Int, B> for N
+        where
+            B: AsBound<$Int> + Contains<PInt<N>> + Contains<NInt<N>>,
+            N: Unsigned + crate::typenum::NonZero + ToI128,
+        {
+            fn check() {
+                check_constant::<$Int, B, PInt<N>>();
+                check_constant::<$Int, B, NInt<N>>();
+            }
+        }
+    };
+}
+
+impl_signed_constant!(i8);
+impl_signed_constant!(i16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expr::{Arg, BitAnd, BitOr, Eq, Ge, Gt, Le, Lt, Ne, Range, RangeInclusive, Rem},
+        typenum::consts::*,
+    };
+
+    #[test]
+    fn exhaustive_i8() {
+        check_exhaustive::<i8, Range<N100, P100>>();
+        check_exhaustive::<i8, Ne<Arg, N128>>();
+        check_exhaustive::<i8, BitOr<Lt<Arg, N5>, Gt<Arg, P5>>>();
+        check_exhaustive::<i8, Eq<Rem<Arg, P7>, Z0>>();
+        check_exhaustive::<i8, BitAnd<Ge<Arg, Z0>, Eq<Rem<Arg, P7>, P3>>>();
+    }
+
+    #[test]
+    fn exhaustive_u8() {
+        check_exhaustive::<u8, RangeInclusive<U0, U255>>();
+        check_exhaustive::<u8, Le<Arg, U9>>();
+        check_exhaustive::<u8, BitAnd<Ne<Arg, U9>, Eq<U1, Rem<Arg, U4>>>>();
+    }
+
+    #[test]
+    fn exhaustive_16_bits() {
+        check_exhaustive_below::<u16, Range<U200, U1000>, U8>();
+        check_exhaustive_below::<i16, Eq<Arg, N1000>, U8>();
+    }
+
+    #[test]
+    fn contains_10_bits() {
+        check_contains_below::<u16, BitAnd<Ge<Arg, U300>, Eq<Rem<Arg, U1000>, U7>>, U10>();
+        check_contains_below::<i16, BitOr<Lt<Arg, N1000>, Eq<Rem<Arg, P300>, N7>>, U10>();
+    }
+
+    #[test]
+    fn for_all_values() {
+        for_all(|x: Bounded<u16, Eq<Rem<Arg, U1000>, U7>>| *x % 1000 == 7);
+        let mut count = 0;
+        for_all(|_: Bounded<i16, Ge<Arg, Z0>>| {
+            count += 1;
+            true
+        });
+        assert_eq!(32768, count);
+    }
+
+    #[test]
+    #[should_panic(expected = "counterexample")]
+    fn for_all_counterexample() {
+        for_all(|x: Bounded<u8, Lt<Arg, U10>>| *x < 9);
+    }
+}
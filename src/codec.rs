@@ -0,0 +1,304 @@
+//! Bit oriented encoding of bounded values using the minimal number of bits.
+//!
+//! A `Bounded<T, B>` is written as its position among the values of the bound's
+//! `Interval` sharing the bound's `Stride`, using exactly `ceil(log2(cardinality))` bits.
+//! Bits are written from the most significant one.
+//!
+//! # Example
+//! ```
+//! use boundnum::{codec::{BitReader, BitWriter}, expr::*, typenum::consts::*, Bounded};
+//!
+//! type Hour = Bounded<u8, Range<U0, U24>>;
+//! type Even = Bounded<u16, BitAnd<Range<U0, U64>, Eq<Rem<Arg, U2>, U0>>>;
+//!
+//! let mut buf = [0u8; 2];
+//! let mut writer = BitWriter::new(&mut buf);
+//! writer.write(Hour::new::<U23>()).unwrap();
+//! writer.write(Even::new::<U62>()).unwrap();
+//! assert_eq!(10, writer.bit_len());
+//!
+//! let mut reader = BitReader::new(&buf);
+//! assert_eq!(23, reader.read::<Hour>().unwrap());
+//! assert_eq!(62, reader.read::<Even>().unwrap());
+//! ```
+
+use crate::{
+    expr::{ExactOf, Interval, IsExact, MaxOf, MinOf, ResidueOf, StepOf, Stride},
+    packed::{Offset, Packable},
+    value::ToI128,
+    Bounded,
+};
+use core::{fmt, marker::PhantomData};
+
+/// A bound whose values can be encoded with the minimal number of bits.
+pub trait Encodable<T>: Packable<T> {
+    /// The offset of the first encodable value from `Packable::MIN`.
+    const ALIGN: u128;
+    /// The distance between encodable values.
+    const STEP: u128;
+    /// The number of encodable values.
+    const CARDINALITY: u128;
+    /// `ceil(log2(CARDINALITY))`
+    const BITS: u32;
+    /// Whether every encodable value satisfies the bound.
+    const EXACT: bool;
+    /// The least code from `code` on of a value which may satisfy the bound,
+    /// or `CARDINALITY` if there is none.
+    fn seek(code: u128) -> u128;
+    /// The greatest code up to `code` of a value which may satisfy the bound.
+    fn seek_back(code: u128) -> Option<u128>;
+}
+
+impl<T, B> Encodable<T> for B
+where
+    B: Packable<T> + Interval<T> + Stride<T> + IsExact<T>,
+    MinOf<B, T>: ToI128,
+    MaxOf<B, T>: ToI128,
+    StepOf<B, T>: ToI128,
+    ResidueOf<B, T>: ToI128,
+{
+    const ALIGN: u128 =
+        (ResidueOf::<B, T>::I128 - MinOf::<B, T>::I128).rem_euclid(StepOf::<B, T>::I128) as u128;
+    const STEP: u128 = StepOf::<B, T>::I128 as u128;
+    const CARDINALITY: u128 = {
+        let span = (MaxOf::<B, T>::I128 - MinOf::<B, T>::I128) as u128;
+        if Self::ALIGN > span {
+            0
+        } else {
+            (span - Self::ALIGN) / Self::STEP + 1
+        }
+    };
+    const BITS: u32 = match Self::CARDINALITY {
+        0 | 1 => 0,
+        cardinality => u128::BITS - (cardinality - 1).leading_zeros(),
+    };
+    const EXACT: bool = <ExactOf<B, T> as crate::typenum::Bit>::BOOL;
+
+    #[inline]
+    fn seek(code: u128) -> u128 {
+        let first = MinOf::<B, T>::I128 + Self::ALIGN as i128;
+        let step = Self::STEP as i128;
+        match B::next_candidate(first + code as i128 * step) {
+            Some(next) => (((next - first + step - 1) / step) as u128).min(Self::CARDINALITY),
+            None => Self::CARDINALITY,
+        }
+    }
+
+    #[inline]
+    fn seek_back(code: u128) -> Option<u128> {
+        let first = MinOf::<B, T>::I128 + Self::ALIGN as i128;
+        let step = Self::STEP as i128;
+        let prev = B::prev_candidate(first + code as i128 * step)?;
+        if prev < first {
+            None
+        } else {
+            Some(((prev - first) / step) as u128)
+        }
+    }
+}
+
+/// A value encoded as a fixed number of bits.
+pub trait Encode: Sized {
+    /// The number of bits.
+    const BITS: u32;
+    fn encode(self) -> u128;
+    /// Returns `None` if `code` is not a valid value.
+    fn decode(code: u128) -> Option<Self>;
+}
+
+impl<T, B> Encode for Bounded<T, B>
+where
+    T: Offset,
+    B: Encodable<T>,
+{
+    const BITS: u32 = B::BITS;
+
+    #[inline]
+    fn encode(self) -> u128 {
+        (self.value().offset_from(B::MIN) as u128 - B::ALIGN) / B::STEP
+    }
+
+    fn decode(code: u128) -> Option<Self> {
+        if code >= B::CARDINALITY {
+            return None;
+        }
+        let value = T::from_offset(B::MIN, (B::ALIGN + code * B::STEP) as u64);
+        if B::contains(&value) {
+            Some(Bounded {
+                value,
+                bound: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// An error of `BitWriter` and `BitReader`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The buffer is too short.
+    UnexpectedEnd,
+    /// The decoded bits are not a valid value.
+    Invalid,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnexpectedEnd => f.write_str("unexpected end of buffer"),
+            Error::Invalid => f.write_str("invalid bounded value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Writes bits into a byte buffer.
+pub struct BitWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        BitWriter { buf, len: 0 }
+    }
+
+    /// Returns the number of written bits.
+    pub fn bit_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of bytes touched by the written bits.
+    pub fn byte_len(&self) -> usize {
+        self.len.div_ceil(8)
+    }
+
+    /// Writes the lowest `bits` bits of `value`.
+    pub fn write_bits(&mut self, value: u128, bits: u32) -> Result<(), Error> {
+        if self.len + bits as usize > self.buf.len() * 8 {
+            return Err(Error::UnexpectedEnd);
+        }
+        for i in (0..bits).rev() {
+            let (byte, shift) = (self.len / 8, 7 - self.len % 8);
+            if (value >> i) & 1 == 1 {
+                self.buf[byte] |= 1 << shift;
+            } else {
+                self.buf[byte] &= !(1 << shift);
+            }
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    pub fn write<E: Encode>(&mut self, value: E) -> Result<(), Error> {
+        self.write_bits(value.encode(), E::BITS)
+    }
+}
+
+/// Reads bits from a byte buffer.
+pub struct BitReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        BitReader { buf, pos: 0 }
+    }
+
+    /// Returns the number of read bits.
+    pub fn bit_position(&self) -> usize {
+        self.pos
+    }
+
+    /// Reads `bits` bits into the lowest bits of the result.
+    pub fn read_bits(&mut self, bits: u32) -> Result<u128, Error> {
+        if self.pos + bits as usize > self.buf.len() * 8 {
+            return Err(Error::UnexpectedEnd);
+        }
+        let mut value = 0;
+        for _ in 0..bits {
+            let (byte, shift) = (self.pos / 8, 7 - self.pos % 8);
+            value = (value << 1) | ((self.buf[byte] >> shift) & 1) as u128;
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+
+    pub fn read<E: Encode>(&mut self) -> Result<E, Error> {
+        E::decode(self.read_bits(E::BITS)?).ok_or(Error::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expr::{Arg, BitAnd, Eq, Ge, Range, RangeInclusive, Rem},
+        typenum::consts::*,
+        value::TypeBound,
+        Boundable,
+    };
+
+    #[test]
+    fn bits() {
+        assert_eq!(5, <Bounded<u8, Range<U0, U24>> as Encode>::BITS);
+        assert_eq!(5, <Bounded<u8, Range<U0, U32>> as Encode>::BITS);
+        assert_eq!(6, <Bounded<u8, Range<U0, U33>> as Encode>::BITS);
+        assert_eq!(0, <Bounded<i32, Eq<Arg, N5>> as Encode>::BITS);
+        assert_eq!(
+            8,
+            <Bounded<i8, Ge<Arg, <i8 as TypeBound>::Min>> as Encode>::BITS
+        );
+        assert_eq!(64, <Bounded<u64, Ge<Arg, U0>> as Encode>::BITS);
+        assert_eq!(7, <Bounded<u8, Eq<U0, Rem<Arg, U2>>> as Encode>::BITS);
+        assert_eq!(
+            2,
+            <Bounded<u8, BitAnd<Range<U0, U20>, Eq<Rem<Arg, U5>, U3>>> as Encode>::BITS
+        );
+    }
+
+    #[test]
+    fn strided_roundtrip() {
+        type B = BitAnd<RangeInclusive<N20, P20>, Eq<Rem<Arg, P3>, P1>>;
+        // `%` keeps the sign of the dividend, so negative values never satisfy the bound.
+        let values = [1, 4, 7, 10, 13, 16, 19];
+        for value in -20i16..=20 {
+            let bounded: Option<Bounded<i16, B>> = value.bound();
+            assert_eq!(values.contains(&value), bounded.is_some());
+            let code = match bounded {
+                Some(bounded) => bounded.encode(),
+                None => continue,
+            };
+            assert_eq!(Some(bounded.unwrap()), Encode::decode(code));
+        }
+        assert_eq!(4, <Bounded<i16, B> as Encode>::BITS);
+    }
+
+    #[test]
+    fn decode_validates() {
+        type Small = Bounded<u8, RangeInclusive<U1, U5>>;
+        assert_eq!(Some(Small::new::<U1>()), Small::decode(0));
+        assert_eq!(None, Small::decode(5));
+
+        let buf = [0xff];
+        let mut reader = BitReader::new(&buf);
+        assert_eq!(Err(Error::Invalid), reader.read::<Small>());
+    }
+
+    #[test]
+    fn buffer_end() {
+        type Byte = Bounded<u8, Ge<Arg, U0>>;
+        let mut buf = [0u8; 1];
+        let mut writer = BitWriter::new(&mut buf);
+        writer.write(Byte::new::<U255>()).unwrap();
+        assert_eq!(Err(Error::UnexpectedEnd), writer.write(Byte::new::<U1>()));
+
+        let mut reader = BitReader::new(&buf);
+        assert_eq!(255, reader.read::<Byte>().unwrap());
+        assert_eq!(Err(Error::UnexpectedEnd), reader.read::<Byte>());
+    }
+}
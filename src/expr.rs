@@ -1,18 +1,44 @@
 //! Expression structs.
+//!
+//! An expression is evaluated by `typenum` in `TypeExpr` for constants, and by Rust operators
+//! in `ValueExpr` for runtime values, with the same results:
+//! `Div` and `Rem` truncate toward zero in both, and `DivEuclid` and `RemEuclid` round toward
+//! negative infinity for positive divisors in both.
+//! Arithmetic and shifts evaluate exactly as `value::Wide` rather than in the argument's type,
+//! as does a constant out of its range, so overflow means the same as in `typenum`:
+//! `Lt<Shl<Arg, U1>, U10>` does not contain `128u8`.
+//! Results beyond `i128` saturate to an infinity of their sign, so they still order
+//! against every constant as in `typenum`.
+//! `check::check_contains` verifies the agreement for every 8-bit constant.
+//!
+//! Other crates can define their own operators with `define_unary_operator!` and
+//! `define_binary_operator!`, which compose with the ones here.
 
 #[macro_use]
 mod macros;
+mod argument;
 mod arith;
 mod bit;
 mod cmp;
+mod const_range;
+mod interval;
+mod logic;
+mod member;
 mod other;
+mod quantifier;
 mod range;
 mod typenum;
 
+pub use argument::*;
 pub use arith::*;
 pub use bit::*;
 pub use cmp::*;
+pub use const_range::*;
+pub use interval::*;
+pub use logic::*;
+pub use member::*;
 pub use other::*;
+pub use quantifier::*;
 pub use range::*;
 
 /// Represents a expression for values.
@@ -43,13 +69,65 @@ impl<A> TypeExpr<A> for Arg {
     type Output = A;
 }
 
+/// Represents the first element of a pair argument.
+///
+/// Constants in a bound on pairs evaluate as the type of both elements.
+///
+/// ```
+/// use boundnum::{expr::*, typenum::consts::*, Boundable, Bounded};
+///
+/// type Span = Bounded<(u32, u32), BitAnd<Le<Fst, Snd>, Le<Add<Fst, Snd>, U100>>>;
+///
+/// let span: Span = (20, 30).bound().unwrap();
+/// let (start, end) = span.value();
+/// assert_eq!((20, 30), (start, end));
+/// assert_eq!((3, 7), Span::new::<(U3, U7)>().value());
+///
+/// let reversed: Option<Span> = (30, 20).bound();
+/// assert!(reversed.is_none());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Fst;
+impl<X, Y> ValueExpr<(X, Y)> for Fst {
+    type Output = X;
+    fn call(arg: (X, Y)) -> Self::Output {
+        arg.0
+    }
+}
+impl<X, Y> TypeExpr<(X, Y)> for Fst {
+    type Output = X;
+}
+
+/// Represents the second element of a pair argument.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Snd;
+impl<X, Y> ValueExpr<(X, Y)> for Snd {
+    type Output = Y;
+    fn call(arg: (X, Y)) -> Self::Output {
+        arg.1
+    }
+}
+impl<X, Y> TypeExpr<(X, Y)> for Snd {
+    type Output = Y;
+}
+
 /// Can use as bound.
-pub trait AsBound<T>: ValueExpr<T, Output = bool> {
-    fn contains(value: T) -> bool {
-        Self::call(value)
+///
+/// The expression is evaluated with the `Argument` of the value.
+/// `Boundable::bound` on primitive integers also infers the `Interval` of the bound,
+/// which every node written by hand must provide, e.g. through `impl_opaque_node!`.
+pub trait AsBound<T> {
+    fn contains(value: &T) -> bool;
+}
+impl<O, T> AsBound<T> for O
+where
+    T: for<'a> Argument<'a>,
+    O: for<'a> ValueExpr<<T as Argument<'a>>::Arg, Output = bool>,
+{
+    fn contains(value: &T) -> bool {
+        O::call(value.arg())
     }
 }
-impl<O, T> AsBound<T> for O where O: ValueExpr<T, Output = bool> {}
 
 /// Call a type expression as bound.
 pub trait Contains<A> {
@@ -69,22 +147,30 @@ mod tests {
     use super::*;
 
     #[test]
-    fn add_is_not_bound() {
+    fn pairs() {
+        use crate::{Boundable, Bounded};
+        use ::typenum::{consts::*, B0, B1};
         use impls::impls;
-        assert!(impls!(Add<Arg, Arg>: !AsBound<i8>));
-        assert!(impls!(Add<Arg, Arg>: !AsBound<i16>));
-        assert!(impls!(Add<Arg, Arg>: !AsBound<i32>));
-        assert!(impls!(Add<Arg, Arg>: !AsBound<i64>));
-        assert!(impls!(Add<Arg, Arg>: !AsBound<isize>));
-        assert!(impls!(Add<Arg, Arg>: !AsBound<u8>));
-        assert!(impls!(Add<Arg, Arg>: !AsBound<u16>));
-        assert!(impls!(Add<Arg, Arg>: !AsBound<u32>));
-        assert!(impls!(Add<Arg, Arg>: !AsBound<u64>));
-        assert!(impls!(Add<Arg, Arg>: !AsBound<usize>));
-    }
-
-    #[test]
-    fn add_valop() {
-        assert_eq!(3 + 2, Add::<Arg, ::typenum::U2>::call(3u32));
+        type Ordered = Le<Fst, Snd>;
+        type SumAtMost100 = Le<Add<Fst, Snd>, U100>;
+        assert!(impls!(Ordered: Contains<(U3, U7), Output = B1>));
+        assert!(impls!(Ordered: Contains<(P3, N7), Output = B0>));
+        assert!(impls!(SumAtMost100: Contains<(U30, U70), Output = B1>));
+        assert!(impls!(SumAtMost100: Contains<(U30, U71), Output = B0>));
+        for a in 0..=120u8 {
+            for b in 0..=120u8 {
+                assert_eq!(a <= b, Boundable::<Ordered>::bound((a, b)).is_some());
+                assert_eq!(
+                    a + b <= 100,
+                    Boundable::<SumAtMost100>::bound((a, b)).is_some()
+                );
+            }
+        }
+        assert!(Boundable::<Lt<Fst, N3>>::bound((-4i8, 0i8)).is_some());
+        assert!(Boundable::<Gt<Snd, P300>>::bound((0i8, 127i8)).is_none());
+        assert_eq!(
+            (-3, 2),
+            Bounded::<(i16, i16), Ordered>::new::<(N3, U2)>().value()
+        );
     }
 }
@@ -0,0 +1,67 @@
+/// Passes a value to the expressions of a bound.
+///
+/// `Copy` values are passed as they are, and others by reference,
+/// e.g. a `String` as a `&str` and a `Vec<T>` as a `&[T]`.
+pub trait Argument<'a> {
+    type Arg: Copy;
+    fn arg(&'a self) -> Self::Arg;
+}
+
+macro_rules! impl_argument_by_value {
+    ( $($Type:ty),* ) => {
+        $(
+            impl<'a> Argument<'a> for $Type {
+                type Arg = $Type;
+                fn arg(&'a self) -> Self::Arg {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_argument_by_value!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool, char);
+
+impl<'a, X: Copy, Y: Copy> Argument<'a> for (X, Y) {
+    type Arg = (X, Y);
+    fn arg(&'a self) -> Self::Arg {
+        *self
+    }
+}
+
+impl<'a, T: Copy, const N: usize> Argument<'a> for [T; N] {
+    type Arg = [T; N];
+    fn arg(&'a self) -> Self::Arg {
+        *self
+    }
+}
+
+impl<'a, 'b, T> Argument<'a> for &'b [T] {
+    type Arg = &'b [T];
+    fn arg(&'a self) -> Self::Arg {
+        self
+    }
+}
+
+impl<'a, 'b> Argument<'a> for &'b str {
+    type Arg = &'b str;
+    fn arg(&'a self) -> Self::Arg {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Argument<'a> for alloc::string::String {
+    type Arg = &'a str;
+    fn arg(&'a self) -> Self::Arg {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: 'a> Argument<'a> for alloc::vec::Vec<T> {
+    type Arg = &'a [T];
+    fn arg(&'a self) -> Self::Arg {
+        self
+    }
+}
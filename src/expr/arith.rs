@@ -1,11 +1,145 @@
-use super::{Call, TypeExpr, ValType, ValueExpr};
+mod helper_traits {
+    use crate::typenum::{
+        Abs, AbsVal, Bit, Diff, Integer, Mod, NInt, NonZero, PInt, Quot, Sum, UInt, UTerm,
+        Unsigned, Z0,
+    };
+    use crate::value::Wide;
+    use core::ops::{Add, Div, Rem, Sub};
+
+    /// Arithmetic of primitive integers, evaluated exactly as `Wide`
+    /// to agree with `typenum` where the argument's type would overflow.
+    pub trait WideArith<R> {
+        type Output;
+        fn add(self, rhs: R) -> Self::Output;
+        fn sub(self, rhs: R) -> Self::Output;
+        fn mul(self, rhs: R) -> Self::Output;
+        fn div(self, rhs: R) -> Self::Output;
+        fn rem(self, rhs: R) -> Self::Output;
+        fn div_euclid(self, rhs: R) -> Self::Output;
+        fn rem_euclid(self, rhs: R) -> Self::Output;
+    }
+
+    impl<L: Into<Wide>, R: Into<Wide>> WideArith<R> for L {
+        type Output = Wide;
+        fn add(self, rhs: R) -> Self::Output {
+            self.into() + rhs.into()
+        }
+
+        fn sub(self, rhs: R) -> Self::Output {
+            self.into() - rhs.into()
+        }
+
+        fn mul(self, rhs: R) -> Self::Output {
+            self.into() * rhs.into()
+        }
+
+        fn div(self, rhs: R) -> Self::Output {
+            self.into() / rhs.into()
+        }
+
+        fn rem(self, rhs: R) -> Self::Output {
+            self.into() % rhs.into()
+        }
+
+        fn div_euclid(self, rhs: R) -> Self::Output {
+            self.into().div_euclid(rhs.into())
+        }
+
+        fn rem_euclid(self, rhs: R) -> Self::Output {
+            self.into().rem_euclid(rhs.into())
+        }
+    }
+
+    /// `core::ops::Neg` of primitive integers, evaluated exactly as `Wide`.
+    pub trait WideNeg {
+        type Output;
+        fn neg(self) -> Self::Output;
+    }
+
+    impl<S: Into<Wide>> WideNeg for S {
+        type Output = Wide;
+        fn neg(self) -> Self::Output {
+            -self.into()
+        }
+    }
+
+    /// `typenum` counterpart of `div_euclid`.
+    pub trait DivEuclidOp<R> {
+        type Output;
+    }
+
+    /// `typenum` counterpart of `rem_euclid`.
+    pub trait RemEuclidOp<R> {
+        type Output;
+    }
+
+    macro_rules! impl_euclid_op_for_unsigned {
+        ( $name:ident $(< $($p:ident),+ >)? $(
+            where
+                $($pb:ident: $first_bound:ident $(+ $rest_bound:ident)* ),+
+        )?) => {
+            impl<R $(, $($p),+)? > DivEuclidOp<R> for $name $(< $($p),+ >)?
+            where
+                Self: Div<R>, $(
+                $($pb: $first_bound $(+ $rest_bound)* ),+
+            )? {
+                type Output = Quot<Self, R>;
+            }
+
+            impl<R $(, $($p),+)? > RemEuclidOp<R> for $name $(< $($p),+ >)?
+            where
+                Self: Rem<R>, $(
+                $($pb: $first_bound $(+ $rest_bound)* ),+
+            )? {
+                type Output = Mod<Self, R>;
+            }
+        };
+    }
+
+    impl_euclid_op_for_unsigned! { UTerm }
+    impl_euclid_op_for_unsigned! { UInt<U, B> where U: Unsigned, B: Bit }
+
+    /// `(self % r + |r|) % |r|`, and `(self - self.rem_euclid(r)) / r`.
+    macro_rules! impl_euclid_op_for_integer {
+        ( $name:ident $(< $($p:ident),+ >)? $(
+            where
+                $($pb:ident: $first_bound:ident $(+ $rest_bound:ident)* ),+
+        )?) => {
+            impl<R $(, $($p),+)? > RemEuclidOp<R> for $name $(< $($p),+ >)?
+            where
+                R: Integer + Abs,
+                Self: Rem<R>,
+                Mod<Self, R>: Add<AbsVal<R>>,
+                Sum<Mod<Self, R>, AbsVal<R>>: Rem<AbsVal<R>>, $(
+                $($pb: $first_bound $(+ $rest_bound)* ),+
+            )? {
+                type Output = Mod<Sum<Mod<Self, R>, AbsVal<R>>, AbsVal<R>>;
+            }
+
+            impl<R $(, $($p),+)? > DivEuclidOp<R> for $name $(< $($p),+ >)?
+            where
+                Self: RemEuclidOp<R> + Sub<<Self as RemEuclidOp<R>>::Output>,
+                Diff<Self, <Self as RemEuclidOp<R>>::Output>: Div<R>, $(
+                $($pb: $first_bound $(+ $rest_bound)* ),+
+            )? {
+                type Output = Quot<Diff<Self, <Self as RemEuclidOp<R>>::Output>, R>;
+            }
+        };
+    }
+
+    impl_euclid_op_for_integer! { Z0 }
+    impl_euclid_op_for_integer! { PInt<U> where U: Unsigned + NonZero }
+    impl_euclid_op_for_integer! { NInt<U> where U: Unsigned + NonZero }
+}
+
+use helper_traits::*;
 
 define_binary_operator! {
     add,
     /// Represents `core::ops::Add`
     Add,
-    core::ops::Add,
-    core::ops::Add::add,
+    WideArith,
+    WideArith::add,
     core::ops::Add,
 }
 
@@ -13,8 +147,8 @@ define_binary_operator! {
     sub,
     /// Represents `core::ops::Sub`
     Sub,
-    core::ops::Sub,
-    core::ops::Sub::sub,
+    WideArith,
+    WideArith::sub,
     core::ops::Sub,
 }
 
@@ -22,8 +156,8 @@ define_binary_operator! {
     mul,
     /// Represents `core::ops::Mul`
     Mul,
-    core::ops::Mul,
-    core::ops::Mul::mul,
+    WideArith,
+    WideArith::mul,
     core::ops::Mul,
 }
 
@@ -31,25 +165,117 @@ define_binary_operator! {
     div,
     /// Represents `core::ops::Div`
     Div,
+    WideArith,
+    WideArith::div,
     core::ops::Div,
-    core::ops::Div::div,
-    core::ops::Div,
+}
+
+/// An alias of `Div`, naming its rounding next to `DivEuclid`:
+/// both `typenum` and Rust truncate toward zero.
+pub type DivTrunc<L, R> = Div<L, R>;
+
+define_binary_operator! {
+    div_euclid,
+    /// Represents `div_euclid` of primitive integers
+    DivEuclid,
+    WideArith,
+    WideArith::div_euclid,
+    DivEuclidOp,
 }
 
 define_binary_operator! {
+    @node
     rem,
     /// Represents `core::ops::Rem`
     Rem,
-    core::ops::Rem,
-    core::ops::Rem::rem,
+    WideArith,
+    WideArith::rem,
     core::ops::Rem,
 }
 
+define_binary_operator! {
+    @node
+    rem_euclid,
+    /// Represents `rem_euclid` of primitive integers
+    RemEuclid,
+    WideArith,
+    WideArith::rem_euclid,
+    RemEuclidOp,
+}
+
 define_unary_operator! {
     neg,
     /// Represents `core::ops::Neg`
     Neg,
+    WideNeg,
+    WideNeg::neg,
     core::ops::Neg,
-    core::ops::Neg::neg,
-    core::ops::Neg,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expr::*;
+
+    #[test]
+    fn add_is_not_bound() {
+        use impls::impls;
+        assert!(impls!(Add<Arg, Arg>: !AsBound<i8>));
+        assert!(impls!(Add<Arg, Arg>: !AsBound<i16>));
+        assert!(impls!(Add<Arg, Arg>: !AsBound<i32>));
+        assert!(impls!(Add<Arg, Arg>: !AsBound<i64>));
+        assert!(impls!(Add<Arg, Arg>: !AsBound<isize>));
+        assert!(impls!(Add<Arg, Arg>: !AsBound<u8>));
+        assert!(impls!(Add<Arg, Arg>: !AsBound<u16>));
+        assert!(impls!(Add<Arg, Arg>: !AsBound<u32>));
+        assert!(impls!(Add<Arg, Arg>: !AsBound<u64>));
+        assert!(impls!(Add<Arg, Arg>: !AsBound<usize>));
+    }
+
+    #[test]
+    fn add_valop() {
+        assert_eq!(Some(3 + 2), Add::<Arg, ::typenum::U2>::call(3u32).get());
+    }
+
+    #[test]
+    fn division_semantics() {
+        use crate::check::{check_contains, check_exhaustive, check_exhaustive_below};
+        use ::typenum::consts::*;
+        check_contains::<i8, Eq<Div<Arg, N7>, P2>>();
+        check_contains::<i8, Eq<Rem<Arg, N7>, N3>>();
+        check_contains::<i8, Eq<DivEuclid<Arg, N7>, P3>>();
+        check_contains::<i8, Eq<DivEuclid<Arg, P7>, N3>>();
+        check_contains::<i8, Eq<RemEuclid<Arg, N7>, P3>>();
+        check_contains::<u8, Eq<DivEuclid<Arg, U7>, U3>>();
+        check_exhaustive::<i8, Eq<RemEuclid<Arg, P7>, P3>>();
+        check_exhaustive_below::<i16, Eq<P3, RemEuclid<Arg, P7>>, U8>();
+    }
+
+    #[test]
+    fn overflow_semantics() {
+        use crate::{
+            check::{check_contains, check_contains_below},
+            Boundable, Bounded,
+        };
+        use ::typenum::{consts::*, B0};
+        use impls::impls;
+        assert!(impls!(Lt<Shl<Arg, U1>, U10>: Contains<U128, Output = B0>));
+        assert!(Boundable::<Lt<Shl<Arg, U1>, U10>>::bound(128u8).is_none());
+        assert!(Bounded::<u8, Lt<Mul<Arg, U2>, U10>>::all()
+            .map(Bounded::value)
+            .eq(0..5));
+        check_contains::<u8, Lt<Shl<Arg, U1>, U10>>();
+        check_contains::<u8, Gt<Shl<Arg, U3>, U200>>();
+        check_contains::<u8, Lt<Shr<Shl<Arg, U4>, U4>, U8>>();
+        check_contains::<u8, Lt<Mul<Arg, U2>, U10>>();
+        check_contains::<u8, Gt<Add<Arg, U200>, U10>>();
+        check_contains::<u8, Eq<Add<Arg, U200>, U300>>();
+        check_contains_below::<u16, Lt<Mul<Arg, Arg>, U100>, U8>();
+        check_contains::<i8, Gt<Mul<Arg, P2>, P100>>();
+        check_contains::<i8, Lt<Sub<Arg, P100>, N100>>();
+        check_contains::<i8, Eq<Neg<Arg>, P128>>();
+        assert!(Boundable::<Gt<Mul<Arg, Arg>, U3>>::bound(u64::MAX).is_some());
+        assert!(Boundable::<Lt<Mul<Arg, Arg>, U3>>::bound(u64::MAX).is_none());
+        assert!(Boundable::<Gt<Shl<Arg, U100>, U3>>::bound(u64::MAX).is_some());
+        assert!(Boundable::<Lt<Neg<Mul<Arg, Arg>>, N3>>::bound(u64::MAX).is_some());
+    }
 }
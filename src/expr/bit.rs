@@ -1,5 +1,193 @@
 use super::{Call, TypeExpr, ValType, ValueExpr};
 
+mod helper_traits {
+    use crate::typenum::{
+        Add1, Bit, Diff, IsEqual, Len, Length, Sum, UInt, UTerm, Unsigned, B0, B1, U0, U1, U128,
+        U16, U32, U64, U8,
+    };
+    use crate::value::{ToUnsigned, UnsignedOf, Wide};
+    use core::ops::{Add, Sub};
+
+    /// Bit-counting intrinsics of primitive integers, returning counts as `Self`
+    /// to compare them with constants, which evaluate as the argument's type.
+    pub trait BitCountExt {
+        /// The width of `Self`, which `leading_zeros` depends on.
+        type Bits;
+        type Output;
+        fn count_ones(self) -> Self::Output;
+        fn leading_zeros(self) -> Self::Output;
+        fn trailing_zeros(self) -> Self::Output;
+    }
+
+    /// `is_power_of_two` of primitive integers, which signed integers lack.
+    pub trait PowerOfTwoExt {
+        type Output;
+        #[allow(clippy::wrong_self_convention)]
+        fn is_power_of_two(self) -> Self::Output;
+    }
+
+    macro_rules! impl_bit_count_ext {
+        ( $Int:ty, $Bits:ty, |$x:ident| $is_power_of_two:expr ) => {
+            impl BitCountExt for $Int {
+                type Bits = $Bits;
+                type Output = $Int;
+                fn count_ones(self) -> Self::Output {
+                    <$Int>::count_ones(self) as $Int
+                }
+
+                fn leading_zeros(self) -> Self::Output {
+                    <$Int>::leading_zeros(self) as $Int
+                }
+
+                fn trailing_zeros(self) -> Self::Output {
+                    <$Int>::trailing_zeros(self) as $Int
+                }
+            }
+
+            impl PowerOfTwoExt for $Int {
+                type Output = bool;
+                fn is_power_of_two(self) -> Self::Output {
+                    let $x = self;
+                    $is_power_of_two
+                }
+            }
+        };
+    }
+
+    impl_bit_count_ext!(u8, U8, |x| x.is_power_of_two());
+    impl_bit_count_ext!(u16, U16, |x| x.is_power_of_two());
+    impl_bit_count_ext!(u32, U32, |x| x.is_power_of_two());
+    impl_bit_count_ext!(u64, U64, |x| x.is_power_of_two());
+    impl_bit_count_ext!(u128, U128, |x| x.is_power_of_two());
+    impl_bit_count_ext!(i8, U8, |x| x > 0 && x.count_ones() == 1);
+    impl_bit_count_ext!(i16, U16, |x| x > 0 && x.count_ones() == 1);
+    impl_bit_count_ext!(i32, U32, |x| x > 0 && x.count_ones() == 1);
+    impl_bit_count_ext!(i64, U64, |x| x > 0 && x.count_ones() == 1);
+    impl_bit_count_ext!(i128, U128, |x| x > 0 && x.count_ones() == 1);
+
+    #[cfg(target_pointer_width = "16")]
+    impl_bit_count_ext!(usize, U16, |x| x.is_power_of_two());
+    #[cfg(target_pointer_width = "16")]
+    impl_bit_count_ext!(isize, U16, |x| x > 0 && x.count_ones() == 1);
+    #[cfg(target_pointer_width = "32")]
+    impl_bit_count_ext!(usize, U32, |x| x.is_power_of_two());
+    #[cfg(target_pointer_width = "32")]
+    impl_bit_count_ext!(isize, U32, |x| x > 0 && x.count_ones() == 1);
+    #[cfg(target_pointer_width = "64")]
+    impl_bit_count_ext!(usize, U64, |x| x.is_power_of_two());
+    #[cfg(target_pointer_width = "64")]
+    impl_bit_count_ext!(isize, U64, |x| x > 0 && x.count_ones() == 1);
+
+    /// Shifts of primitive integers, evaluated exactly as `Wide` without losing bits
+    /// to agree with `typenum`.
+    pub trait WideShift<R> {
+        type Output;
+        fn shl(self, rhs: R) -> Self::Output;
+        fn shr(self, rhs: R) -> Self::Output;
+    }
+
+    impl<L: Into<Wide>, R: Into<Wide>> WideShift<R> for L {
+        type Output = Wide;
+        fn shl(self, rhs: R) -> Self::Output {
+            self.into() << rhs.into()
+        }
+
+        fn shr(self, rhs: R) -> Self::Output {
+            self.into() >> rhs.into()
+        }
+    }
+
+    /// Counts the ones of a `typenum::Unsigned`.
+    pub trait Ones {
+        type Output;
+    }
+
+    pub type OnesOf<U> = <U as Ones>::Output;
+
+    impl Ones for UTerm {
+        type Output = U0;
+    }
+
+    impl<U: Unsigned + Ones, B: Bit> Ones for UInt<U, B>
+    where
+        OnesOf<U>: Add<B>,
+    {
+        type Output = Sum<OnesOf<U>, B>;
+    }
+
+    /// Counts the trailing zeros of a nonzero `typenum::Unsigned`.
+    pub trait Trailing {
+        type Output;
+    }
+
+    pub type TrailingOf<U> = <U as Trailing>::Output;
+
+    impl<U: Unsigned> Trailing for UInt<U, B1> {
+        type Output = U0;
+    }
+
+    impl<U: Unsigned + Trailing> Trailing for UInt<U, B0>
+    where
+        TrailingOf<U>: Add<B1>,
+    {
+        type Output = Add1<TrailingOf<U>>;
+    }
+
+    /// `typenum` counterpart of `count_ones` for non-negative constants.
+    pub trait CountOnesOp {
+        type Output;
+    }
+
+    impl<C: ToUnsigned> CountOnesOp for C
+    where
+        UnsignedOf<C>: Ones,
+    {
+        type Output = OnesOf<UnsignedOf<C>>;
+    }
+
+    /// `typenum` counterpart of `trailing_zeros` for positive constants.
+    ///
+    /// Zero has as many trailing zeros as the width of its type, which constants do not have.
+    pub trait TrailingZerosOp {
+        type Output;
+    }
+
+    impl<C: ToUnsigned> TrailingZerosOp for C
+    where
+        UnsignedOf<C>: Trailing,
+    {
+        type Output = TrailingOf<UnsignedOf<C>>;
+    }
+
+    /// `typenum` counterpart of `leading_zeros` for non-negative constants within `Bits` bits.
+    pub trait LeadingZerosOp<Bits> {
+        type Output;
+    }
+
+    impl<C: ToUnsigned, Bits> LeadingZerosOp<Bits> for C
+    where
+        UnsignedOf<C>: Len,
+        Bits: Sub<Length<UnsignedOf<C>>>,
+    {
+        type Output = Diff<Bits, Length<UnsignedOf<C>>>;
+    }
+
+    /// `typenum` counterpart of `is_power_of_two` for non-negative constants.
+    pub trait IsPowerOfTwoOp {
+        type Output;
+    }
+
+    impl<C: ToUnsigned> IsPowerOfTwoOp for C
+    where
+        UnsignedOf<C>: Ones,
+        OnesOf<UnsignedOf<C>>: IsEqual<U1>,
+    {
+        type Output = crate::typenum::Eq<OnesOf<UnsignedOf<C>>, U1>;
+    }
+}
+
+use helper_traits::*;
+
 define_unary_operator! {
     not,
     /// Represents `core::ops::Not`
@@ -10,6 +198,7 @@ define_unary_operator! {
 }
 
 define_binary_operator! {
+    @node
     bitand,
     /// Represents `core::ops::BitAnd`
     BitAnd,
@@ -18,7 +207,10 @@ define_binary_operator! {
     core::ops::BitAnd,
 }
 
+define_unary_operator! { @operand BitAnd<L, R> }
+
 define_binary_operator! {
+    @node
     bitor,
     /// Represents `core::ops::BitOr`
     BitOr,
@@ -27,6 +219,8 @@ define_binary_operator! {
     core::ops::BitOr,
 }
 
+define_unary_operator! { @operand BitOr<L, R> }
+
 define_binary_operator! {
     bitxor,
     /// Represents `core::ops::BitXor`
@@ -40,8 +234,8 @@ define_binary_operator! {
     shl,
     /// Represents `core::ops::Shl`
     Shl,
-    core::ops::Shl,
-    core::ops::Shl::shl,
+    WideShift,
+    WideShift::shl,
     core::ops::Shl,
 }
 
@@ -49,7 +243,144 @@ define_binary_operator! {
     shr,
     /// Represents `core::ops::Shr`
     Shr,
+    WideShift,
+    WideShift::shr,
     core::ops::Shr,
-    core::ops::Shr::shr,
-    core::ops::Shr,
+}
+
+define_unary_operator! {
+    count_ones,
+    /// Represents `count_ones` of primitive integers
+    CountOnes,
+    BitCountExt,
+    BitCountExt::count_ones,
+    CountOnesOp,
+}
+
+define_unary_operator! {
+    trailing_zeros,
+    /// Represents `trailing_zeros` of primitive integers
+    ///
+    /// Only positive constants are evaluated at the type level,
+    /// as the trailing zeros of zero depend on the width of its type.
+    TrailingZeros,
+    BitCountExt,
+    BitCountExt::trailing_zeros,
+    TrailingZerosOp,
+}
+
+/// Represents `leading_zeros` of primitive integers whose width is `Bits`
+///
+/// The width is explicit as constants do not have one,
+/// so `LeadingZeros<Arg, U8>` evaluates only for `u8` and `i8`.
+///
+/// ```
+/// use boundnum::{expr::*, typenum::consts::*, Boundable, Bounded};
+///
+/// type High4BitsClear = Bounded<u8, Ge<LeadingZeros<Arg, U8>, U4>>;
+///
+/// assert_eq!(15, High4BitsClear::new::<U15>().value());
+/// assert_eq!(None, Boundable::<Ge<LeadingZeros<Arg, U8>, U4>>::bound(16u8));
+/// ```
+///
+/// ```compile_fail
+/// use boundnum::{expr::*, typenum::consts::*, Bounded};
+///
+/// let x = Bounded::<u16, Ge<LeadingZeros<Arg, U8>, U4>>::new::<U15>();
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LeadingZeros<S, Bits> {
+    operand: core::marker::PhantomData<S>,
+    bits: core::marker::PhantomData<Bits>,
+}
+
+impl<A, S, Bits> ValueExpr<A> for LeadingZeros<S, Bits>
+where
+    S: ValueExpr<A>,
+    ValType<S, A>: BitCountExt<Bits = Bits>,
+{
+    type Output = <ValType<S, A> as BitCountExt>::Output;
+
+    fn call(arg: A) -> Self::Output {
+        BitCountExt::leading_zeros(S::call(arg))
+    }
+}
+
+impl<A, S, Bits> TypeExpr<A> for LeadingZeros<S, Bits>
+where
+    S: TypeExpr<A>,
+    Call<S, A>: LeadingZerosOp<Bits>,
+{
+    type Output = <Call<S, A> as LeadingZerosOp<Bits>>::Output;
+}
+
+define_unary_operator! {
+    is_power_of_two,
+    /// Represents `is_power_of_two`, which is false for non-positive values of signed integers
+    IsPowerOfTwo,
+    PowerOfTwoExt,
+    PowerOfTwoExt::is_power_of_two,
+    IsPowerOfTwoOp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::{check_contains, check_contains_below},
+        expr::{And, Arg, Eq, Ge, Gt, Le, Or},
+        typenum::{consts::*, B0, B1},
+        Boundable, Bounded,
+    };
+    use impls::impls;
+
+    #[test]
+    fn value_level() {
+        assert_eq!(3, CountOnes::<Arg>::call(0b1011_0000u8));
+        assert_eq!(8, CountOnes::<Arg>::call(-1i8));
+        assert_eq!(4, TrailingZeros::<Arg>::call(0b1011_0000u8));
+        assert_eq!(16, TrailingZeros::<Arg>::call(0u16));
+        assert_eq!(2, LeadingZeros::<Arg, U8>::call(0b0011_0000u8));
+        assert_eq!(26, LeadingZeros::<Arg, U32>::call(32i32));
+        assert!(IsPowerOfTwo::<Arg>::call(64i8));
+        assert!(!IsPowerOfTwo::<Arg>::call(-64i8));
+        assert!(!IsPowerOfTwo::<Arg>::call(0u64));
+        assert!(Boundable::<IsPowerOfTwo<Arg>>::bound(1u32 << 31).is_some());
+        assert!(Boundable::<Le<CountOnes<Arg>, U3>>::bound(0b1101u32).is_some());
+        assert!(Boundable::<Le<CountOnes<Arg>, U3>>::bound(0b1111u32).is_none());
+    }
+
+    #[test]
+    fn type_level() {
+        assert!(impls!(CountOnes<Arg>: TypeExpr<U11, Output = U3>));
+        assert!(impls!(CountOnes<Arg>: TypeExpr<P11, Output = U3>));
+        assert!(impls!(CountOnes<Arg>: TypeExpr<U0, Output = U0>));
+        assert!(impls!(TrailingZeros<Arg>: TypeExpr<U40, Output = U3>));
+        assert!(impls!(TrailingZeros<Arg>: !TypeExpr<U0>));
+        assert!(impls!(LeadingZeros<Arg, U8>: TypeExpr<U40, Output = U2>));
+        assert!(impls!(LeadingZeros<Arg, U8>: TypeExpr<Z0, Output = U8>));
+        assert!(impls!(LeadingZeros<Arg, U8>: !TypeExpr<U256>));
+        assert!(impls!(IsPowerOfTwo<Arg>: TypeExpr<U64, Output = B1>));
+        assert!(impls!(IsPowerOfTwo<Arg>: TypeExpr<U0, Output = B0>));
+        assert!(impls!(IsPowerOfTwo<Arg>: !TypeExpr<N64>));
+        assert_eq!(
+            256,
+            Bounded::<u32, IsPowerOfTwo<Arg>>::new::<U256>().value()
+        );
+        assert!(impls!(LeadingZeros<Arg, U8>: !ValueExpr<u16>));
+    }
+
+    #[test]
+    fn exhaustive() {
+        type LowBitsClear = Or<Eq<Arg, U0>, Ge<TrailingZeros<Arg>, U4>>;
+        check_contains::<u8, Le<CountOnes<Arg>, U3>>();
+        check_contains::<u8, LowBitsClear>();
+        check_contains::<u8, Ge<LeadingZeros<Arg, U8>, U3>>();
+        check_contains::<u8, IsPowerOfTwo<Arg>>();
+        check_contains_below::<u16, Eq<LeadingZeros<Arg, U16>, U9>, U8>();
+        check_contains::<i8, And<Gt<Arg, Z0>, Eq<CountOnes<Arg>, U2>>>();
+        check_contains::<i8, And<Gt<Arg, Z0>, Le<TrailingZeros<Arg>, U1>>>();
+        check_contains::<i8, And<Ge<Arg, Z0>, Eq<LeadingZeros<Arg, U8>, U3>>>();
+        check_contains_below::<i16, And<Ge<Arg, Z0>, IsPowerOfTwo<Arg>>, U8>();
+    }
 }
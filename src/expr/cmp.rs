@@ -1,7 +1,8 @@
-use super::{Call, TypeExpr, ValType, ValueExpr};
-
 mod helper_traits {
-    pub trait OrdExt<R>: core::cmp::Ord {
+    use crate::typenum;
+    use crate::value::{IntegerOf, ToInteger, Wide};
+
+    pub trait OrdExt<R> {
         type Output;
         fn cmp(self, rhs: R) -> Self::Output;
     }
@@ -57,7 +58,93 @@ mod helper_traits {
         }
     }
 
-    pub trait MinMax<R>: core::cmp::Ord {
+    /// Compares primitive integers with `Wide` values by widening them.
+    macro_rules! impl_ext_for_wide {
+        ( $($Int:ty),* ) => {
+            $(
+                impl_ext_for_wide!(@ $Int, Wide, Wide::from);
+                impl_ext_for_wide!(@ Wide, $Int, Wide::from);
+            )*
+        };
+        ( @ $L:ty, $R:ty, $wide:path ) => {
+            impl OrdExt<$R> for $L {
+                type Output = core::cmp::Ordering;
+                fn cmp(self, rhs: $R) -> Self::Output {
+                    OrdExt::cmp($wide(self), $wide(rhs))
+                }
+            }
+
+            impl PartialEqExt<$R> for $L {
+                type Output = bool;
+                fn eq(self, rhs: $R) -> Self::Output {
+                    $wide(self) == $wide(rhs)
+                }
+
+                fn ne(self, rhs: $R) -> Self::Output {
+                    $wide(self) != $wide(rhs)
+                }
+            }
+
+            impl PartialOrdExt<$R> for $L {
+                type Output = bool;
+                fn gt(self, rhs: $R) -> Self::Output {
+                    $wide(self) > $wide(rhs)
+                }
+
+                fn ge(self, rhs: $R) -> Self::Output {
+                    $wide(self) >= $wide(rhs)
+                }
+
+                fn lt(self, rhs: $R) -> Self::Output {
+                    $wide(self) < $wide(rhs)
+                }
+
+                fn le(self, rhs: $R) -> Self::Output {
+                    $wide(self) <= $wide(rhs)
+                }
+            }
+
+            impl MinMax<$R> for $L {
+                type Output = Wide;
+                fn max(self, rhs: $R) -> Self::Output {
+                    MinMax::max($wide(self), $wide(rhs))
+                }
+
+                fn min(self, rhs: $R) -> Self::Output {
+                    MinMax::min($wide(self), $wide(rhs))
+                }
+            }
+        };
+    }
+
+    impl_ext_for_wide!(i8, i16, i32, i64, isize, u8, u16, u32, u64, u128, usize);
+
+    macro_rules! define_integer_comparison {
+        ( $name:ident, $op:ident ) => {
+            pub trait $name<R> {
+                type Output;
+            }
+
+            impl<L, R> $name<R> for L
+            where
+                L: ToInteger,
+                R: ToInteger,
+                IntegerOf<L>: typenum::$op<IntegerOf<R>>,
+            {
+                type Output = <IntegerOf<L> as typenum::$op<IntegerOf<R>>>::Output;
+            }
+        };
+    }
+
+    define_integer_comparison!(CmpExt, Cmp);
+    define_integer_comparison!(IsEqualExt, IsEqual);
+    define_integer_comparison!(IsNotEqualExt, IsNotEqual);
+    define_integer_comparison!(IsGreaterExt, IsGreater);
+    define_integer_comparison!(IsGreaterOrEqualExt, IsGreaterOrEqual);
+    define_integer_comparison!(IsLessExt, IsLess);
+    define_integer_comparison!(IsLessOrEqualExt, IsLessOrEqual);
+
+    pub trait MinMax<R> {
         type Output;
         fn max(self, rhs: R) -> Self::Output;
         fn min(self, rhs: R) -> Self::Output;
@@ -77,69 +164,88 @@ mod helper_traits {
 
 use helper_traits::*;
 
+// Comparisons convert typenum constants to `typenum::Integer`,
+// so that a bound can compare constants of any sign with the argument.
 define_binary_operator! {
+    @node
     cmp,
-    /// Represents `core::cmp::Ord::cmp`
+    /// Represents `core::cmp::Ord::cmp`, which yields `typenum::Less`, `Equal` or `Greater`
+    /// at the type level
     Cmp,
     OrdExt,
     OrdExt::cmp,
-    typenum::Cmp,
+    CmpExt,
 }
 
 define_binary_operator! {
+    @node
     eq,
     /// Represents `core::cmp::PartialEq::eq`
     Eq,
     PartialEqExt,
     PartialEqExt::eq,
-    typenum::IsEqual,
+    IsEqualExt,
 }
 
+define_unary_operator! { @operand Eq<L, R> }
+
 define_binary_operator! {
     ne,
     /// Represents `core::cmp::PartialEq::ne`
     Ne,
     PartialEqExt,
     PartialEqExt::ne,
-    typenum::IsNotEqual,
+    IsNotEqualExt,
 }
 
 define_binary_operator! {
+    @node
     gt,
     /// Represents `core::cmp::PartialOrd::gt`
     Gt,
     PartialOrdExt,
     PartialOrdExt::gt,
-    typenum::IsGreater,
+    IsGreaterExt,
 }
 
+define_unary_operator! { @operand Gt<L, R> }
+
 define_binary_operator! {
+    @node
     ge,
     /// Represents `core::cmp::PartialOrd::ge`
     Ge,
     PartialOrdExt,
     PartialOrdExt::ge,
-    typenum::IsGreaterOrEqual,
+    IsGreaterOrEqualExt,
 }
 
+define_unary_operator! { @operand Ge<L, R> }
+
 define_binary_operator! {
+    @node
     lt,
     /// Represents `core::cmp::PartialOrd::lt`
     Lt,
     PartialOrdExt,
     PartialOrdExt::lt,
-    typenum::IsLess,
+    IsLessExt,
 }
 
+define_unary_operator! { @operand Lt<L, R> }
+
 define_binary_operator! {
+    @node
     le,
     /// Represents `core::cmp::PartialOrd::le`
     Le,
     PartialOrdExt,
     PartialOrdExt::le,
-    typenum::IsLessOrEqual,
+    IsLessOrEqualExt,
 }
 
+define_unary_operator! { @operand Le<L, R> }
+
 define_binary_operator! {
     max,
     /// Represents `core::cmp::Ord::max`
@@ -157,3 +263,39 @@ define_binary_operator! {
     MinMax::min,
     typenum::Min,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::expr::*;
+
+    #[test]
+    fn ordering_constants() {
+        use crate::{check::check_exhaustive, value::ToValue, Bounded};
+        use ::typenum::{consts::*, Equal, Greater, Less};
+        use core::cmp::Ordering;
+        use impls::impls;
+        assert_eq!(Ordering::Less, <Less as ToValue<Ordering>>::VALUE);
+        assert_eq!(Ordering::Greater, Greater::call(0u8));
+        assert!(impls!(Cmp<Arg, U3>: TypeExpr<P2, Output = Less>));
+        assert!(impls!(Eq<Cmp<Arg, U3>, Less>: Contains<U2, Output = ::typenum::B1>));
+        assert!(impls!(Eq<Cmp<Arg, U3>, Less>: Contains<P3, Output = ::typenum::B0>));
+        assert!(impls!(Ge<Cmp<Arg, N3>, Equal>: Contains<Z0, Output = ::typenum::B1>));
+        assert_eq!(
+            2,
+            Bounded::<i8, Eq<Cmp<Arg, U3>, Less>>::new::<U2>().value()
+        );
+        assert_eq!(Ordering::Less, Cmp::<Arg, P300>::call(7u8));
+        assert_eq!(Ordering::Greater, Cmp::<Arg, N1>::call(0u8));
+        check_exhaustive::<u8, Eq<Cmp<Arg, U3>, Less>>();
+        check_exhaustive::<i8, Eq<Cmp<Arg, N3>, Greater>>();
+        crate::check::check_contains::<i8, Eq<Cmp<Arg, P200>, Equal>>();
+        check_exhaustive::<u8, Ne<Cmp<Arg, U9>, Equal>>();
+        crate::check::check_contains_below::<i16, Le<Cmp<Arg, N7>, Equal>, U8>();
+        assert!(crate::Boundable::<Eq<Cmp<Mul<Arg, Arg>, U3>, Less>>::bound(u64::MAX).is_none());
+        assert!(crate::Boundable::<Eq<Cmp<Mul<Arg, Arg>, U3>, Greater>>::bound(u64::MAX).is_some());
+        assert_eq!(
+            Ordering::Less,
+            Cmp::<Neg<Mul<Arg, Arg>>, N3>::call(u64::MAX)
+        );
+    }
+}
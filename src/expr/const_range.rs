@@ -0,0 +1,37 @@
+use super::ValueExpr;
+use crate::value::Wide;
+
+/// `START..END` with const generic parameters.
+///
+/// It is only evaluated on values, so `Bounded::new` and `CONST!` are not available.
+/// Convert from or into `Bounded<T, Range<Start, End>>` to use them.
+///
+/// ```
+/// use boundnum::{expr::*, typenum::consts::*, Bounded, Boundable};
+///
+/// let hour: Bounded<u8, ConstRange<0, 24>> = 13.bound().unwrap();
+/// let typed: Bounded<u8, Range<U0, U24>> = hour.into();
+/// assert_eq!(13, typed.value());
+/// ```
+///
+/// Converting between different ranges fails to compile.
+/// ```compile_fail
+/// use boundnum::{expr::*, typenum::consts::*, Bounded, Boundable};
+///
+/// let hour: Bounded<u8, ConstRange<0, 24>> = 13.bound().unwrap();
+/// let typed: Bounded<u8, Range<U0, U23>> = hour.into();
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ConstRange<const START: i64, const END: i64>;
+
+impl<A, const START: i64, const END: i64> ValueExpr<A> for ConstRange<START, END>
+where
+    A: Into<Wide>,
+{
+    type Output = bool;
+
+    fn call(arg: A) -> Self::Output {
+        let arg = arg.into();
+        Wide::from(START) <= arg && arg < Wide::from(END)
+    }
+}
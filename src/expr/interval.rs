@@ -0,0 +1,888 @@
+use super::*;
+use crate::typenum::{
+    Abs, AbsVal, Add1, Bit, Diff, Equal, Greater, IsEqual, IsGreaterOrEqual, IsLess, IsLessOrEqual,
+    Less, Maximum, Minimum, Mod, NInt, NonZero, PInt, Quot, Sum, UInt, UTerm, Unsigned, B0, B1, U1,
+    Z0,
+};
+use crate::value::{
+    IntegerOf, Pred, PredOf, Succ, SuccOf, ToInteger, ToUnsigned, TypeBound, UnsignedOf,
+};
+use core::ops::{Add, Div, Rem as RemOp, Sub};
+
+/// Classifies an operand of a comparison,
+/// so that comparisons of `Arg` with constants infer their `Interval` and `Stride`.
+///
+/// Nodes of `define_unary_operator!` and `define_binary_operator!` are `OpaqueOperand`s.
+pub trait Operand {
+    type Kind;
+}
+
+/// The `Operand::Kind` of `Arg`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ArgOperand;
+
+/// The `Operand::Kind` of `typenum` constants.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ConstOperand;
+
+/// The `Operand::Kind` of `Rem`, `RemEuclid` and `Cmp` of `Arg` and a constant.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OfArgOperand;
+
+/// The `Operand::Kind` of any other expression.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OpaqueOperand;
+
+impl Operand for Arg {
+    type Kind = ArgOperand;
+}
+
+macro_rules! impl_const_operand {
+    ( $( [$($g:tt)*] $name:ty ),* $(,)? ) => {
+        $(
+            impl<$($g)*> Operand for $name {
+                type Kind = ConstOperand;
+            }
+        )*
+    };
+}
+
+impl_const_operand! {
+    [] UTerm,
+    [U: Unsigned, B: Bit] UInt<U, B>,
+    [] Z0,
+    [U: Unsigned + NonZero] PInt<U>,
+    [U: Unsigned + NonZero] NInt<U>,
+    [] B0,
+    [] B1,
+    [] Less,
+    [] Equal,
+    [] Greater,
+}
+
+macro_rules! impl_of_arg_operand {
+    ( $($name:ident),* ) => {
+        $(
+            impl<L: Operand, R: Operand> Operand for $name<L, R>
+            where
+                (L::Kind, R::Kind): helper_traits::Pair,
+                helper_traits::PairOf<L, R>: helper_traits::OfArg,
+            {
+                type Kind = <helper_traits::PairOf<L, R> as helper_traits::OfArg>::Output;
+            }
+        )*
+    };
+}
+
+impl_of_arg_operand!(Rem, RemEuclid, Cmp);
+
+macro_rules! impl_opaque_operand {
+    ( $( $name:ident $(< $($p:ident),+ >)? ),* $(,)? ) => {
+        $(
+            impl $(< $($p),+ >)? Operand for $name $(< $($p),+ >)? {
+                type Kind = OpaqueOperand;
+            }
+        )*
+    };
+}
+
+impl_opaque_operand! {
+    Fst,
+    Snd,
+    LeadingZeros<S, Bits>,
+    And<L, R>,
+    Or<L, R>,
+    Implies<L, R>,
+    Xor<L, R>,
+    If<Cond, Then, Else>,
+    OneOf<List>,
+    NoneOf<List>,
+    ForAll<B>,
+    Any<B>,
+}
+
+impl<const START: i64, const END: i64> Operand for ConstRange<START, END> {
+    type Kind = OpaqueOperand;
+}
+
+/// Infers the closed interval `Min..=Max` containing every value which satisfies a bound.
+///
+/// The interval may be wider than the bound itself, e.g. for `Ne` or `BitOr`.
+/// Bounds without inference span the whole type, so that `Bounded::all` scans every value.
+pub trait Interval<T> {
+    type Min;
+    type Max;
+
+    /// The least value from `value` on which may satisfy the bound, or `None` if there is none,
+    /// so that `Bounded::all` skips the values in between.
+    #[inline]
+    fn next_candidate(value: i128) -> Option<i128> {
+        Some(value)
+    }
+
+    /// The greatest value up to `value` which may satisfy the bound, or `None` if there is none.
+    #[inline]
+    fn prev_candidate(value: i128) -> Option<i128> {
+        Some(value)
+    }
+}
+/// Get an `Interval`'s lower end.
+pub type MinOf<B, T> = <B as Interval<T>>::Min;
+/// Get an `Interval`'s upper end.
+pub type MaxOf<B, T> = <B as Interval<T>>::Max;
+
+/// Computes `Max - Min` of an `Interval` as `typenum::Unsigned`.
+///
+/// Empty intervals have no span.
+pub trait Span<T>: Interval<T> {
+    type Output: Unsigned;
+}
+/// Get a `Span`'s output type.
+pub type SpanOf<B, T> = <B as Span<T>>::Output;
+
+impl<T, B> Span<T> for B
+where
+    B: Interval<T>,
+    MaxOf<B, T>: Sub<MinOf<B, T>>,
+    Diff<MaxOf<B, T>, MinOf<B, T>>: ToUnsigned,
+{
+    type Output = UnsignedOf<Diff<MaxOf<B, T>, MinOf<B, T>>>;
+}
+
+/// Computes the number of values of an `Interval` congruent to its `Stride`
+/// as `typenum::Unsigned`, the type-level `codec::Encodable::CARDINALITY`.
+pub trait Cardinality<T>: Span<T> + Stride<T> {
+    type Output: Unsigned;
+}
+/// Get a `Cardinality`'s output type.
+pub type CardinalityOf<B, T> = <B as Cardinality<T>>::Output;
+
+/// The first value congruent to the stride is `Align` above `Min`.
+impl<T, B> Cardinality<T> for B
+where
+    B: Span<T> + Stride<T>,
+    ResidueOf<B, T>: helper_traits::Align<MinOf<B, T>, StepOf<B, T>>,
+    SpanOf<B, T>: Sub<helper_traits::AlignOf<B, T>>,
+    Diff<SpanOf<B, T>, helper_traits::AlignOf<B, T>>: Div<StepOf<B, T>>,
+    Quot<Diff<SpanOf<B, T>, helper_traits::AlignOf<B, T>>, StepOf<B, T>>: Add<B1>,
+    Add1<Quot<Diff<SpanOf<B, T>, helper_traits::AlignOf<B, T>>, StepOf<B, T>>>: Unsigned,
+{
+    type Output = Add1<Quot<Diff<SpanOf<B, T>, helper_traits::AlignOf<B, T>>, StepOf<B, T>>>;
+}
+
+/// Marks bounds whose `Interval` is not empty.
+///
+/// Bounds whose interval is empty can never hold a value.
+pub trait NonEmpty<T>: Interval<T> {}
+
+impl<T, B> NonEmpty<T> for B
+where
+    B: Interval<T>,
+    MinOf<B, T>: IsLessOrEqual<MaxOf<B, T>, Output = B1>,
+{
+}
+
+/// Marks bounds which a value of `T` may satisfy, so that `Boundable::bound`
+/// rejects a bound which never holds at compile time.
+///
+/// Bounds on primitive integers must be `NonEmpty`, and bounds on other types,
+/// whose intervals are not inferred, always may hold.
+pub trait Satisfiable<T> {}
+
+impl<T: TypeBound, B: NonEmpty<T>> Satisfiable<T> for B {}
+
+macro_rules! impl_satisfiable_for_uninferred {
+    ( $([$($g:tt)*] $Type:ty),* ) => {
+        $(
+            impl<$($g)*> Satisfiable<$Type> for B {}
+        )*
+    };
+}
+
+impl_satisfiable_for_uninferred! {
+    [B] i128, [B] u128, [B] bool, [B] char,
+    [B, X, Y] (X, Y), [B, T, const N: usize] [T; N], ['a, B, T] &'a [T], ['a, B] &'a str
+}
+
+#[cfg(feature = "alloc")]
+impl_satisfiable_for_uninferred! { [B] alloc::string::String, [B, T] alloc::vec::Vec<T> }
+
+impl<T: TypeBound> Interval<T> for B1 {
+    type Min = T::Min;
+    type Max = T::Max;
+}
+
+impl<T, L, R> Interval<T> for BitAnd<L, R>
+where
+    L: Interval<T>,
+    R: Interval<T>,
+    MinOf<L, T>: crate::typenum::Max<MinOf<R, T>>,
+    MaxOf<L, T>: crate::typenum::Min<MaxOf<R, T>>,
+{
+    type Min = Maximum<MinOf<L, T>, MinOf<R, T>>;
+    type Max = Minimum<MaxOf<L, T>, MaxOf<R, T>>;
+}
+
+impl<T, L, R> Interval<T> for BitOr<L, R>
+where
+    L: Interval<T>,
+    R: Interval<T>,
+    MinOf<L, T>: crate::typenum::Min<MinOf<R, T>>,
+    MaxOf<L, T>: crate::typenum::Max<MaxOf<R, T>>,
+{
+    type Min = Minimum<MinOf<L, T>, MinOf<R, T>>;
+    type Max = Maximum<MaxOf<L, T>, MaxOf<R, T>>;
+}
+
+/// Infers a `Step` and a `Residue` such that every value which satisfies a bound
+/// is congruent to `Residue` modulo `Step`.
+pub trait Stride<T> {
+    type Step;
+    type Residue;
+}
+/// Get a `Stride`'s step.
+pub type StepOf<B, T> = <B as Stride<T>>::Step;
+/// Get a `Stride`'s residue.
+pub type ResidueOf<B, T> = <B as Stride<T>>::Residue;
+
+macro_rules! impl_unit_stride {
+    ( $name:ident $(< $($p:ident),+ >)? ) => {
+        impl<T: TypeBound $(, $($p),+)? > Stride<T> for $name $(< $($p),+ >)? {
+            type Step = U1;
+            type Residue = T::Min;
+        }
+    };
+}
+
+impl_unit_stride! { B1 }
+impl_unit_stride! { BitOr<L, R> }
+
+/// Uses the stride of `L` unless it is trivial.
+impl<T, L, R> Stride<T> for BitAnd<L, R>
+where
+    L: Stride<T>,
+    R: Stride<T>,
+    StepOf<L, T>: IsEqual<U1>,
+    crate::typenum::Eq<StepOf<L, T>, U1>: helper_traits::Select<StepOf<R, T>, StepOf<L, T>>
+        + helper_traits::Select<ResidueOf<R, T>, ResidueOf<L, T>>,
+{
+    type Step = <crate::typenum::Eq<StepOf<L, T>, U1> as helper_traits::Select<
+        StepOf<R, T>,
+        StepOf<L, T>,
+    >>::Output;
+    type Residue = <crate::typenum::Eq<StepOf<L, T>, U1> as helper_traits::Select<
+        ResidueOf<R, T>,
+        ResidueOf<L, T>,
+    >>::Output;
+}
+
+/// Infers whether every value of a bound's `Interval` congruent to its `Stride`
+/// satisfies the bound.
+///
+/// Inferred conservatively: `Ne`, `BitOr` and remainders of negative values are never exact.
+pub trait IsExact<T> {
+    type Output: Bit;
+}
+/// Get an `IsExact`'s output type.
+pub type ExactOf<B, T> = <B as IsExact<T>>::Output;
+
+impl<T> IsExact<T> for B1 {
+    type Output = B1;
+}
+
+impl<T, L, R> IsExact<T> for BitOr<L, R> {
+    type Output = B0;
+}
+
+/// Exact if both sides are, and at most one side has a nontrivial stride.
+impl<T, L, R> IsExact<T> for BitAnd<L, R>
+where
+    L: IsExact<T> + Stride<T>,
+    R: IsExact<T> + Stride<T>,
+    StepOf<L, T>: IsEqual<U1>,
+    StepOf<R, T>: IsEqual<U1>,
+    ExactOf<L, T>: core::ops::BitAnd<ExactOf<R, T>>,
+    crate::typenum::Eq<StepOf<L, T>, U1>: core::ops::BitOr<crate::typenum::Eq<StepOf<R, T>, U1>>,
+    crate::typenum::And<ExactOf<L, T>, ExactOf<R, T>>: core::ops::BitAnd<
+        crate::typenum::Or<
+            crate::typenum::Eq<StepOf<L, T>, U1>,
+            crate::typenum::Eq<StepOf<R, T>, U1>,
+        >,
+    >,
+    crate::typenum::And<
+        crate::typenum::And<ExactOf<L, T>, ExactOf<R, T>>,
+        crate::typenum::Or<
+            crate::typenum::Eq<StepOf<L, T>, U1>,
+            crate::typenum::Eq<StepOf<R, T>, U1>,
+        >,
+    >: Bit,
+{
+    type Output = crate::typenum::And<
+        crate::typenum::And<ExactOf<L, T>, ExactOf<R, T>>,
+        crate::typenum::Or<
+            crate::typenum::Eq<StepOf<L, T>, U1>,
+            crate::typenum::Eq<StepOf<R, T>, U1>,
+        >,
+    >;
+}
+
+/// Marks bounds satisfied by every value of their `Interval` congruent to their `Stride`.
+pub trait Exact<T>: Interval<T> + Stride<T> {}
+
+impl<T, B> Exact<T> for B where B: Interval<T> + Stride<T> + IsExact<T, Output = B1> {}
+
+/// Marks bounds satisfied by every value of `T`.
+pub trait Tautology<T>: Exact<T> {}
+
+impl<T, B> Tautology<T> for B
+where
+    T: TypeBound,
+    B: Exact<T>,
+    MinOf<B, T>: IsEqual<T::Min, Output = B1>,
+    MaxOf<B, T>: IsEqual<T::Max, Output = B1>,
+    StepOf<B, T>: IsEqual<U1, Output = B1>,
+{
+}
+
+/// Comparisons of `Arg` with a constant on either side infer a range,
+/// `Eq` of `Rem`, `RemEuclid` or `Cmp` of `Arg` with a constant infers a stride or a range,
+/// and any other comparison spans the whole type.
+macro_rules! impl_comparison {
+    ( $($name:ident),* ) => {
+        $(
+            impl<T, L, R> Interval<T> for $name<L, R>
+            where
+                Self: helper_traits::Normal,
+                helper_traits::NormalOf<Self>: Interval<T>,
+            {
+                type Min = MinOf<helper_traits::NormalOf<Self>, T>;
+                type Max = MaxOf<helper_traits::NormalOf<Self>, T>;
+            }
+
+            impl<T, L, R> Stride<T> for $name<L, R>
+            where
+                Self: helper_traits::Normal,
+                helper_traits::NormalOf<Self>: Stride<T>,
+            {
+                type Step = StepOf<helper_traits::NormalOf<Self>, T>;
+                type Residue = ResidueOf<helper_traits::NormalOf<Self>, T>;
+            }
+
+            impl<T, L, R> IsExact<T> for $name<L, R>
+            where
+                Self: helper_traits::Normal,
+                helper_traits::NormalOf<Self>: IsExact<T>,
+            {
+                type Output = ExactOf<helper_traits::NormalOf<Self>, T>;
+            }
+        )*
+    };
+}
+
+impl_comparison!(Ge, Gt, Le, Lt, Eq);
+
+/// `B0` never holds, so any interval is sound.
+impl<T: TypeBound> Interval<T> for B0 {
+    type Min = T::Min;
+    type Max = T::Max;
+}
+
+impl_unit_stride! { B0 }
+
+impl<T> IsExact<T> for B0 {
+    type Output = B0;
+}
+
+define_unary_operator! { @bound Xor<L, R> }
+define_unary_operator! { @bound Implies<L, R> }
+
+impl<T: TypeBound, const START: i64, const END: i64> Interval<T> for ConstRange<START, END> {
+    type Min = T::Min;
+    type Max = T::Max;
+}
+
+impl<T: TypeBound, const START: i64, const END: i64> Stride<T> for ConstRange<START, END> {
+    type Step = U1;
+    type Residue = T::Min;
+}
+
+impl<T, const START: i64, const END: i64> IsExact<T> for ConstRange<START, END> {
+    type Output = B0;
+}
+
+mod helper_traits {
+    use super::*;
+
+    /// Chooses `Then` for `B1` and `Else` for `B0`.
+    pub trait Select<Then, Else> {
+        type Output;
+    }
+
+    impl<Then, Else> Select<Then, Else> for B1 {
+        type Output = Then;
+    }
+
+    impl<Then, Else> Select<Then, Else> for B0 {
+        type Output = Else;
+    }
+
+    /// Computes `(Self - Min).rem_euclid(Step)` of constants of any kind.
+    pub trait Align<Min, Step> {
+        type Output: Unsigned;
+    }
+
+    pub type AlignOf<B, T> = <ResidueOf<B, T> as Align<MinOf<B, T>, StepOf<B, T>>>::Output;
+
+    type Offset<C, Min> = Diff<IntegerOf<C>, IntegerOf<Min>>;
+
+    impl<C, Min, Step> Align<Min, Step> for C
+    where
+        C: ToInteger,
+        Min: ToInteger,
+        Step: Unsigned + NonZero,
+        IntegerOf<C>: Sub<IntegerOf<Min>>,
+        Offset<C, Min>: RemOp<PInt<Step>>,
+        Mod<Offset<C, Min>, PInt<Step>>: Add<PInt<Step>>,
+        Sum<Mod<Offset<C, Min>, PInt<Step>>, PInt<Step>>: RemOp<PInt<Step>>,
+        Mod<Sum<Mod<Offset<C, Min>, PInt<Step>>, PInt<Step>>, PInt<Step>>: ToUnsigned,
+    {
+        type Output = UnsignedOf<Mod<Sum<Mod<Offset<C, Min>, PInt<Step>>, PInt<Step>>, PInt<Step>>>;
+    }
+
+    /// Classes of the two operands of a comparison.
+    pub struct ArgConst;
+    pub struct ConstArg;
+    pub struct OfArgConst;
+    pub struct ConstOfArg;
+    pub struct Opaque;
+
+    /// Classifies a pair of `Operand::Kind`s.
+    pub trait Pair {
+        type Output;
+    }
+
+    pub type PairOf<L, R> = <(<L as Operand>::Kind, <R as Operand>::Kind) as Pair>::Output;
+
+    macro_rules! impl_pair {
+        ( $( [$($g:ident),*] ($L:ty, $R:ty) => $Output:ty ),* $(,)? ) => {
+            $(
+                impl<$($g),*> Pair for ($L, $R) {
+                    type Output = $Output;
+                }
+            )*
+        };
+    }
+
+    impl_pair! {
+        [] (ArgOperand, ConstOperand) => ArgConst,
+        [] (ConstOperand, ArgOperand) => ConstArg,
+        [] (OfArgOperand, ConstOperand) => OfArgConst,
+        [] (ConstOperand, OfArgOperand) => ConstOfArg,
+        [] (ArgOperand, ArgOperand) => Opaque,
+        [] (ArgOperand, OfArgOperand) => Opaque,
+        [] (ArgOperand, OpaqueOperand) => Opaque,
+        [] (ConstOperand, ConstOperand) => Opaque,
+        [] (ConstOperand, OpaqueOperand) => Opaque,
+        [] (OfArgOperand, ArgOperand) => Opaque,
+        [] (OfArgOperand, OfArgOperand) => Opaque,
+        [] (OfArgOperand, OpaqueOperand) => Opaque,
+        [K] (OpaqueOperand, K) => Opaque,
+    }
+
+    /// The `Operand::Kind` of a node whose operands are of the class `Self`.
+    pub trait OfArg {
+        type Output;
+    }
+
+    impl OfArg for ArgConst {
+        type Output = OfArgOperand;
+    }
+
+    macro_rules! impl_opaque_of_arg {
+        ( $($Class:ty),* ) => {
+            $(
+                impl OfArg for $Class {
+                    type Output = OpaqueOperand;
+                }
+            )*
+        };
+    }
+
+    impl_opaque_of_arg!(ConstArg, OfArgConst, ConstOfArg, Opaque);
+
+    /// Leaf bounds which comparisons rewrite into.
+    pub struct ArgGe<C>(core::marker::PhantomData<C>);
+    pub struct ArgGt<C>(core::marker::PhantomData<C>);
+    pub struct ArgLe<C>(core::marker::PhantomData<C>);
+    pub struct ArgLt<C>(core::marker::PhantomData<C>);
+    pub struct ArgEq<C>(core::marker::PhantomData<C>);
+    /// `Eq<Rem<Arg, N>, C>`
+    pub struct RemEq<N, C>(core::marker::PhantomData<(N, C)>);
+    /// `Eq<RemEuclid<Arg, N>, C>`
+    pub struct RemEuclidEq<N, C>(core::marker::PhantomData<(N, C)>);
+    /// Any other comparison.
+    pub struct Unknown;
+
+    /// Rewrites a comparison whose operands are of the class `Class` into a leaf bound.
+    pub trait Normalize<Class> {
+        type Output;
+    }
+
+    /// Rewrites a comparison into a leaf bound.
+    pub trait Normal {
+        type Output;
+    }
+
+    pub type NormalOf<B> = <B as Normal>::Output;
+
+    macro_rules! impl_normal {
+        ( $( $name:ident, $ArgConst:ident, $ConstArg:ident );* $(;)? ) => {
+            $(
+                impl<L: Operand, R: Operand> Normal for $name<L, R>
+                where
+                    (L::Kind, R::Kind): Pair,
+                    Self: Normalize<PairOf<L, R>>,
+                {
+                    type Output = <Self as Normalize<PairOf<L, R>>>::Output;
+                }
+
+                impl<C> Normalize<ArgConst> for $name<Arg, C> {
+                    type Output = $ArgConst<C>;
+                }
+
+                impl<C> Normalize<ConstArg> for $name<C, Arg> {
+                    type Output = $ConstArg<C>;
+                }
+
+                impl<L, R> Normalize<Opaque> for $name<L, R> {
+                    type Output = Unknown;
+                }
+            )*
+        };
+    }
+
+    impl_normal! {
+        Ge, ArgGe, ArgLe;
+        Gt, ArgGt, ArgLt;
+        Le, ArgLe, ArgGe;
+        Lt, ArgLt, ArgGt;
+        Eq, ArgEq, ArgEq;
+    }
+
+    macro_rules! impl_normal_of_arg {
+        ( $($name:ident),* ) => {
+            $(
+                impl<L, R> Normalize<OfArgConst> for $name<L, R> {
+                    type Output = Unknown;
+                }
+
+                impl<L, R> Normalize<ConstOfArg> for $name<L, R> {
+                    type Output = Unknown;
+                }
+            )*
+        };
+    }
+
+    impl_normal_of_arg!(Ge, Gt, Le, Lt);
+
+    /// Rewrites `Eq<Self, C>` of a node of the kind `OfArgOperand` into a leaf bound.
+    pub trait EqLeaf<C> {
+        type Output;
+    }
+
+    impl<X: EqLeaf<C>, C> Normalize<OfArgConst> for Eq<X, C> {
+        type Output = X::Output;
+    }
+
+    impl<C, X: EqLeaf<C>> Normalize<ConstOfArg> for Eq<C, X> {
+        type Output = X::Output;
+    }
+
+    impl<N, C> EqLeaf<C> for Rem<Arg, N> {
+        type Output = RemEq<N, C>;
+    }
+
+    impl<N, C> EqLeaf<C> for RemEuclid<Arg, N> {
+        type Output = RemEuclidEq<N, C>;
+    }
+
+    /// `Eq<Cmp<Arg, C>, Less>` is the same bound as `Lt<Arg, C>`, and so on.
+    impl<C> EqLeaf<Less> for Cmp<Arg, C> {
+        type Output = ArgLt<C>;
+    }
+
+    impl<C> EqLeaf<Equal> for Cmp<Arg, C> {
+        type Output = ArgEq<C>;
+    }
+
+    impl<C> EqLeaf<Greater> for Cmp<Arg, C> {
+        type Output = ArgGt<C>;
+    }
+
+    /// Clamped to `TypeBound`, as the constant may be out of `T`'s range or of another sign.
+    impl<T: TypeBound, C> Interval<T> for ArgGe<C>
+    where
+        C: Lower<T::Min>,
+        LowerOf<C, T>: crate::typenum::Max<T::Min>,
+    {
+        type Min = Maximum<LowerOf<C, T>, T::Min>;
+        type Max = T::Max;
+    }
+
+    impl<T: TypeBound, C: Succ> Interval<T> for ArgGt<C>
+    where
+        SuccOf<C>: Lower<T::Min>,
+        LowerOf<SuccOf<C>, T>: crate::typenum::Max<T::Min>,
+    {
+        type Min = Maximum<LowerOf<SuccOf<C>, T>, T::Min>;
+        type Max = T::Max;
+    }
+
+    impl<T: TypeBound, C> Interval<T> for ArgLe<C>
+    where
+        C: Upper<T::Min>,
+        UpperOf<C, T>: crate::typenum::Min<T::Max>,
+    {
+        type Min = T::Min;
+        type Max = Minimum<UpperOf<C, T>, T::Max>;
+    }
+
+    impl<T: TypeBound, C> Interval<T> for ArgLt<C>
+    where
+        C: Upper<T::Min>,
+        UpperOf<C, T>: Pred,
+        PredOf<UpperOf<C, T>>: crate::typenum::Min<T::Max>,
+    {
+        type Min = T::Min;
+        type Max = Minimum<PredOf<UpperOf<C, T>>, T::Max>;
+    }
+
+    /// Clamped to `TypeBound` like `ArgGe` and `ArgLe`, so that it is empty out of range.
+    impl<T: TypeBound, C> Interval<T> for ArgEq<C>
+    where
+        C: Lower<T::Min> + Upper<T::Min>,
+        LowerOf<C, T>: crate::typenum::Max<T::Min>,
+        UpperOf<C, T>: crate::typenum::Min<T::Max>,
+    {
+        type Min = Maximum<LowerOf<C, T>, T::Min>;
+        type Max = Minimum<UpperOf<C, T>, T::Max>;
+    }
+
+    impl<T: TypeBound, C> Stride<T> for ArgEq<C> {
+        type Step = U1;
+        type Residue = C;
+    }
+
+    macro_rules! impl_exact_range {
+        ( $($name:ident),* ) => {
+            $(
+                impl_unit_stride! { $name<C> }
+
+                impl<T, C> IsExact<T> for $name<C> {
+                    type Output = B1;
+                }
+            )*
+        };
+    }
+
+    impl_exact_range!(ArgGe, ArgGt, ArgLe, ArgLt);
+
+    impl<T, C> IsExact<T> for ArgEq<C> {
+        type Output = B1;
+    }
+
+    /// The step of remainders of `N`, which is `|N|`.
+    pub type Modulus<N> = UnsignedOf<AbsVal<IntegerOf<N>>>;
+
+    /// Whether `0 <= Self < |N|`, so that `Self` is a remainder of `N`.
+    pub trait IsRemainder<N> {
+        type Output: Bit;
+    }
+
+    impl<C, N> IsRemainder<N> for C
+    where
+        C: ToInteger,
+        N: ToInteger,
+        IntegerOf<N>: Abs,
+        IntegerOf<C>: IsGreaterOrEqual<Z0> + IsLess<AbsVal<IntegerOf<N>>>,
+        crate::typenum::GrEq<IntegerOf<C>, Z0>:
+            core::ops::BitAnd<crate::typenum::Le<IntegerOf<C>, AbsVal<IntegerOf<N>>>>,
+        crate::typenum::And<
+            crate::typenum::GrEq<IntegerOf<C>, Z0>,
+            crate::typenum::Le<IntegerOf<C>, AbsVal<IntegerOf<N>>>,
+        >: Bit,
+    {
+        type Output = crate::typenum::And<
+            crate::typenum::GrEq<IntegerOf<C>, Z0>,
+            crate::typenum::Le<IntegerOf<C>, AbsVal<IntegerOf<N>>>,
+        >;
+    }
+
+    macro_rules! impl_remainder {
+        ( $($name:ident),* ) => {
+            $(
+                impl<T: TypeBound, N, C> Interval<T> for $name<N, C> {
+                    type Min = T::Min;
+                    type Max = T::Max;
+                }
+
+                impl<T, N, C> Stride<T> for $name<N, C>
+                where
+                    N: ToInteger,
+                    IntegerOf<N>: Abs,
+                    AbsVal<IntegerOf<N>>: ToUnsigned,
+                {
+                    type Step = Modulus<N>;
+                    type Residue = C;
+                }
+            )*
+        };
+    }
+
+    impl_remainder!(RemEq, RemEuclidEq);
+
+    /// `x % N == C` holds for every `x` congruent to `C` unless `C` is not a remainder,
+    /// or `x` is negative and `C` is not zero.
+    impl<T, N, C> IsExact<T> for RemEq<N, C>
+    where
+        T: TypeBound,
+        T::Min: NonNegative,
+        C: IsRemainder<N> + ToInteger,
+        IntegerOf<C>: IsEqual<Z0>,
+        crate::typenum::Eq<IntegerOf<C>, Z0>: core::ops::BitOr<<T::Min as NonNegative>::Output>,
+        <C as IsRemainder<N>>::Output: core::ops::BitAnd<
+            crate::typenum::Or<
+                crate::typenum::Eq<IntegerOf<C>, Z0>,
+                <T::Min as NonNegative>::Output,
+            >,
+        >,
+        crate::typenum::And<
+            <C as IsRemainder<N>>::Output,
+            crate::typenum::Or<
+                crate::typenum::Eq<IntegerOf<C>, Z0>,
+                <T::Min as NonNegative>::Output,
+            >,
+        >: Bit,
+    {
+        type Output = crate::typenum::And<
+            <C as IsRemainder<N>>::Output,
+            crate::typenum::Or<
+                crate::typenum::Eq<IntegerOf<C>, Z0>,
+                <T::Min as NonNegative>::Output,
+            >,
+        >;
+    }
+
+    /// `x.rem_euclid(N) == C` holds for every `x` congruent to `C` unless `C` is not a remainder.
+    impl<T, N, C: IsRemainder<N>> IsExact<T> for RemEuclidEq<N, C> {
+        type Output = C::Output;
+    }
+
+    impl<T: TypeBound> Interval<T> for Unknown {
+        type Min = T::Min;
+        type Max = T::Max;
+    }
+
+    impl_unit_stride! { Unknown }
+
+    impl<T> IsExact<T> for Unknown {
+        type Output = B0;
+    }
+
+    /// Converts a constant used as a lower end to the kind of `Kind`, the minimum of a type.
+    ///
+    /// Negative constants become zero for unsigned types.
+    pub trait Lower<Kind> {
+        type Output;
+    }
+
+    pub type LowerOf<C, T> = <C as Lower<<T as TypeBound>::Min>>::Output;
+
+    /// Converts a constant used as an upper end to the kind of `Kind`, the minimum of a type.
+    ///
+    /// Negative constants are rejected for unsigned types.
+    pub trait Upper<Kind> {
+        type Output;
+    }
+
+    pub type UpperOf<C, T> = <C as Upper<<T as TypeBound>::Min>>::Output;
+
+    macro_rules! impl_kind_conversion {
+        ( [$($g:tt)*] $Kind:ty, $name:ty => $Output:ty ) => {
+            impl<$($g)*> Lower<$Kind> for $name {
+                type Output = $Output;
+            }
+
+            impl<$($g)*> Upper<$Kind> for $name {
+                type Output = $Output;
+            }
+        };
+    }
+
+    // Unsigned types.
+    impl_kind_conversion! { [] UTerm, UTerm => UTerm }
+    impl_kind_conversion! { [U: Unsigned, B: Bit] UTerm, UInt<U, B> => UInt<U, B> }
+    impl_kind_conversion! { [] UTerm, Z0 => UTerm }
+    impl_kind_conversion! { [U: Unsigned + NonZero] UTerm, PInt<U> => U }
+
+    impl<U: Unsigned + NonZero> Lower<UTerm> for NInt<U> {
+        type Output = UTerm;
+    }
+
+    // Signed types.
+    impl_kind_conversion! { [K: Unsigned + NonZero] NInt<K>, UTerm => Z0 }
+    impl_kind_conversion! {
+        [K: Unsigned + NonZero, U: Unsigned, B: Bit] NInt<K>, UInt<U, B> => PInt<UInt<U, B>>
+    }
+    impl_kind_conversion! { [K: Unsigned + NonZero] NInt<K>, Z0 => Z0 }
+    impl_kind_conversion! { [K: Unsigned + NonZero, U: Unsigned + NonZero] NInt<K>, PInt<U> => PInt<U> }
+    impl_kind_conversion! { [K: Unsigned + NonZero, U: Unsigned + NonZero] NInt<K>, NInt<U> => NInt<U> }
+
+    /// Whether a typenum constant is not negative.
+    pub trait NonNegative {
+        type Output: Bit;
+    }
+
+    impl NonNegative for UTerm {
+        type Output = B1;
+    }
+
+    impl<U: Unsigned + NonZero> NonNegative for NInt<U> {
+        type Output = B0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_empty() {
+        use ::typenum::consts::*;
+        use impls::impls;
+        assert!(impls!(Range<U3, U5>: NonEmpty<u8>));
+        assert!(impls!(RangeInclusive<U3, U3>: NonEmpty<u8>));
+        assert!(impls!(Range<U5, U3>: !NonEmpty<u8>));
+        assert!(impls!(Range<U3, U3>: !NonEmpty<u8>));
+        assert!(impls!(BitAnd<Lt<Arg, U3>, Gt<Arg, U7>>: !NonEmpty<u8>));
+        assert!(impls!(BitAnd<Lt<Arg, N3>, Gt<Arg, P7>>: !NonEmpty<i8>));
+        assert!(impls!(BitOr<Lt<Arg, N3>, Gt<Arg, P7>>: NonEmpty<i8>));
+        assert!(impls!(Lt<Arg, U0>: !NonEmpty<u8>));
+        assert!(impls!(Eq<Arg, U300>: !NonEmpty<u8>));
+        assert!(impls!(Eq<Arg, N300>: !NonEmpty<i8>));
+        assert!(impls!(Eq<Arg, U255>: NonEmpty<u8>));
+        assert!(impls!(Range<U5, U3>: !Satisfiable<u8>));
+        assert!(impls!(Range<U5, U3>: Satisfiable<(u8, u8)>));
+    }
+
+    #[test]
+    fn range_interval() {
+        use crate::value::ToValue;
+        use ::typenum::consts::*;
+        type B = BitAnd<Range<N3, P7>, Gt<Arg, Z0>>;
+        assert_eq!(1, <MinOf<B, i8> as ToValue<i8>>::VALUE);
+        assert_eq!(6, <MaxOf<B, i8> as ToValue<i8>>::VALUE);
+        assert_eq!(0, <MinOf<Le<Arg, U9>, u8> as ToValue<u8>>::VALUE);
+        assert_eq!(255, <MaxOf<Ne<Arg, U9>, u8> as ToValue<u8>>::VALUE);
+    }
+}
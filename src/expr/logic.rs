@@ -0,0 +1,370 @@
+use super::*;
+use crate::typenum::{B0, B1};
+
+mod helper_traits {
+    use super::*;
+    use crate::value::Wide;
+
+    /// Evaluates `R` only if `Self` does not decide `L && R`.
+    pub trait AndThen<R, A> {
+        type Output;
+    }
+
+    impl<R, A> AndThen<R, A> for B0 {
+        type Output = B0;
+    }
+
+    impl<R: TypeExpr<A>, A> AndThen<R, A> for B1 {
+        type Output = Call<R, A>;
+    }
+
+    /// Evaluates `R` only if `Self` does not decide `L || R`.
+    pub trait OrElse<R, A> {
+        type Output;
+    }
+
+    impl<R: TypeExpr<A>, A> OrElse<R, A> for B0 {
+        type Output = Call<R, A>;
+    }
+
+    impl<R, A> OrElse<R, A> for B1 {
+        type Output = B1;
+    }
+
+    /// Evaluates `R` only if `Self` does not decide `!L || R`.
+    pub trait Imply<R, A> {
+        type Output;
+    }
+
+    impl<R, A> Imply<R, A> for B0 {
+        type Output = B1;
+    }
+
+    impl<R: TypeExpr<A>, A> Imply<R, A> for B1 {
+        type Output = Call<R, A>;
+    }
+
+    /// Evaluates only the branch `Self` picks.
+    pub trait Branch<Then, Else, A> {
+        type Output;
+    }
+
+    impl<Then: TypeExpr<A>, Else, A> Branch<Then, Else, A> for B1 {
+        type Output = Call<Then, A>;
+    }
+
+    impl<Then, Else: TypeExpr<A>, A> Branch<Then, Else, A> for B0 {
+        type Output = Call<Else, A>;
+    }
+
+    /// The type of the values of both branches, `Wide` if only one of them is.
+    pub trait Join<Else> {
+        type Output;
+        fn then(self) -> Self::Output;
+        fn else_(value: Else) -> Self::Output;
+    }
+
+    impl<T> Join<T> for T {
+        type Output = T;
+        fn then(self) -> T {
+            self
+        }
+
+        fn else_(value: T) -> T {
+            value
+        }
+    }
+
+    macro_rules! impl_join_for_wide {
+        ( $($Int:ty),* ) => {
+            $(
+                impl Join<$Int> for Wide {
+                    type Output = Wide;
+                    fn then(self) -> Wide {
+                        self
+                    }
+
+                    fn else_(value: $Int) -> Wide {
+                        Wide::from(value)
+                    }
+                }
+
+                impl Join<Wide> for $Int {
+                    type Output = Wide;
+                    fn then(self) -> Wide {
+                        Wide::from(self)
+                    }
+
+                    fn else_(value: Wide) -> Wide {
+                        value
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_join_for_wide!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+}
+
+macro_rules! define_logical_operator {
+    (
+        $(#[$meta:meta])*
+        $name:ident,
+        |$lhs:ident, $rhs:ident| $val_expr:expr,
+        $type_operator:path,
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy)]
+        pub struct $name<L, R> {
+            lhs: core::marker::PhantomData<L>,
+            rhs: core::marker::PhantomData<R>,
+        }
+
+        impl<A, L, R> ValueExpr<A> for $name<L, R>
+        where
+            A: Copy,
+            L: ValueExpr<A, Output = bool>,
+            R: ValueExpr<A, Output = bool>,
+        {
+            type Output = bool;
+
+            fn call(arg: A) -> Self::Output {
+                let ($lhs, $rhs) = (|| L::call(arg), || R::call(arg));
+                $val_expr
+            }
+        }
+
+        impl<A, L, R> TypeExpr<A> for $name<L, R>
+        where
+            L: TypeExpr<A>,
+            Call<L, A>: $type_operator,
+        {
+            type Output = <Call<L, A> as $type_operator>::Output;
+        }
+    };
+}
+
+define_logical_operator! {
+    /// Represents `&&`, which evaluates `R` only if `L` is true.
+    And,
+    |lhs, rhs| lhs() && rhs(),
+    helper_traits::AndThen<R, A>,
+}
+
+define_logical_operator! {
+    /// Represents `||`, which evaluates `R` only if `L` is false.
+    Or,
+    |lhs, rhs| lhs() || rhs(),
+    helper_traits::OrElse<R, A>,
+}
+
+define_logical_operator! {
+    /// Represents `!L || R`, which evaluates `R` only if `L` is true.
+    Implies,
+    |lhs, rhs| !lhs() || rhs(),
+    helper_traits::Imply<R, A>,
+}
+
+/// Represents `L != R` on `bool`s, which always evaluates both sides.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Xor<L, R> {
+    lhs: core::marker::PhantomData<L>,
+    rhs: core::marker::PhantomData<R>,
+}
+
+impl<A, L, R> ValueExpr<A> for Xor<L, R>
+where
+    A: Copy,
+    L: ValueExpr<A, Output = bool>,
+    R: ValueExpr<A, Output = bool>,
+{
+    type Output = bool;
+
+    fn call(arg: A) -> Self::Output {
+        L::call(arg) != R::call(arg)
+    }
+}
+
+impl<A, L, R> TypeExpr<A> for Xor<L, R>
+where
+    L: TypeExpr<A>,
+    R: TypeExpr<A>,
+    Call<L, A>: core::ops::BitXor<Call<R, A>>,
+{
+    type Output = <Call<L, A> as core::ops::BitXor<Call<R, A>>>::Output;
+}
+
+/// Represents `if Cond { Then } else { Else }`, which evaluates only the picked branch.
+///
+/// The branches evaluate as `Wide` if either of them does.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct If<Cond, Then, Else> {
+    cond: core::marker::PhantomData<Cond>,
+    then: core::marker::PhantomData<Then>,
+    else_: core::marker::PhantomData<Else>,
+}
+
+impl<A, Cond, Then, Else> ValueExpr<A> for If<Cond, Then, Else>
+where
+    A: Copy,
+    Cond: ValueExpr<A, Output = bool>,
+    Then: ValueExpr<A>,
+    Else: ValueExpr<A>,
+    ValType<Then, A>: helper_traits::Join<ValType<Else, A>>,
+{
+    type Output = <ValType<Then, A> as helper_traits::Join<ValType<Else, A>>>::Output;
+
+    fn call(arg: A) -> Self::Output {
+        if Cond::call(arg) {
+            helper_traits::Join::then(Then::call(arg))
+        } else {
+            <ValType<Then, A> as helper_traits::Join<ValType<Else, A>>>::else_(Else::call(arg))
+        }
+    }
+}
+
+impl<A, Cond, Then, Else> TypeExpr<A> for If<Cond, Then, Else>
+where
+    Cond: TypeExpr<A>,
+    Call<Cond, A>: helper_traits::Branch<Then, Else, A>,
+{
+    type Output = <Call<Cond, A> as helper_traits::Branch<Then, Else, A>>::Output;
+}
+
+/// Infers the same as `BitOr<Then, Else>`, ignoring the condition.
+impl<T, Cond, Then, Else> Interval<T> for If<Cond, Then, Else>
+where
+    BitOr<Then, Else>: Interval<T>,
+{
+    type Min = MinOf<BitOr<Then, Else>, T>;
+    type Max = MaxOf<BitOr<Then, Else>, T>;
+}
+
+/// Infers the same as `BitOr<Then, Else>`, ignoring the condition.
+impl<T, Cond, Then, Else> Stride<T> for If<Cond, Then, Else>
+where
+    BitOr<Then, Else>: Stride<T>,
+{
+    type Step = StepOf<BitOr<Then, Else>, T>;
+    type Residue = ResidueOf<BitOr<Then, Else>, T>;
+}
+
+impl<T, Cond, Then, Else> IsExact<T> for If<Cond, Then, Else> {
+    type Output = B0;
+}
+
+/// Infers the same as `BitAnd`.
+impl<T, L, R> Interval<T> for And<L, R>
+where
+    BitAnd<L, R>: Interval<T>,
+{
+    type Min = MinOf<BitAnd<L, R>, T>;
+    type Max = MaxOf<BitAnd<L, R>, T>;
+}
+
+/// Infers the same as `BitAnd`.
+impl<T, L, R> Stride<T> for And<L, R>
+where
+    BitAnd<L, R>: Stride<T>,
+{
+    type Step = StepOf<BitAnd<L, R>, T>;
+    type Residue = ResidueOf<BitAnd<L, R>, T>;
+}
+
+/// Infers the same as `BitAnd`.
+impl<T, L, R> IsExact<T> for And<L, R>
+where
+    BitAnd<L, R>: IsExact<T>,
+{
+    type Output = ExactOf<BitAnd<L, R>, T>;
+}
+
+/// Infers the same as `BitOr`.
+impl<T, L, R> Interval<T> for Or<L, R>
+where
+    BitOr<L, R>: Interval<T>,
+{
+    type Min = MinOf<BitOr<L, R>, T>;
+    type Max = MaxOf<BitOr<L, R>, T>;
+}
+
+/// Infers the same as `BitOr`.
+impl<T, L, R> Stride<T> for Or<L, R>
+where
+    BitOr<L, R>: Stride<T>,
+{
+    type Step = StepOf<BitOr<L, R>, T>;
+    type Residue = ResidueOf<BitOr<L, R>, T>;
+}
+
+impl<T, L, R> IsExact<T> for Or<L, R> {
+    type Output = B0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check::check_exhaustive, typenum::consts::*, Boundable, Bounded};
+
+    type Divides100 = And<Ne<Arg, U0>, Eq<Rem<U100, Arg>, U0>>;
+
+    #[test]
+    fn short_circuit() {
+        assert!(Boundable::<Divides100>::bound(0u8).is_none());
+        assert!(Boundable::<Divides100>::bound(25u8).is_some());
+        assert!(Boundable::<Divides100>::bound(30u8).is_none());
+        assert!(Boundable::<Or<Eq<Arg, U0>, Eq<Rem<U100, Arg>, U0>>>::bound(0u8).is_some());
+        assert!(Boundable::<Implies<Ne<Arg, U0>, Eq<Rem<U100, Arg>, U0>>>::bound(0u8).is_some());
+        assert!(Boundable::<Implies<Ne<Arg, U0>, Eq<Rem<U100, Arg>, U0>>>::bound(3u8).is_none());
+    }
+
+    #[test]
+    fn type_level() {
+        use impls::impls;
+        assert_eq!(20, Bounded::<u8, Divides100>::new::<U20>().value());
+        assert!(impls!(Divides100: Contains<U0, Output = B0>));
+        assert!(impls!(Implies<Ne<Arg, U0>, Eq<Rem<U100, Arg>, U0>>: Contains<U0, Output = B1>));
+        assert!(impls!(Xor<Lt<Arg, U5>, Gt<Arg, U3>>: Contains<U4, Output = B0>));
+    }
+
+    type EvenBelow100OddBelow50 = If<Eq<Rem<Arg, U2>, U0>, Lt<Arg, U100>, Lt<Arg, U50>>;
+
+    #[test]
+    fn if_branches() {
+        assert!(Boundable::<EvenBelow100OddBelow50>::bound(98u8).is_some());
+        assert!(Boundable::<EvenBelow100OddBelow50>::bound(49u8).is_some());
+        assert!(Boundable::<EvenBelow100OddBelow50>::bound(51u8).is_none());
+        assert!(Boundable::<EvenBelow100OddBelow50>::bound(100u8).is_none());
+        assert_eq!(
+            98,
+            Bounded::<u8, EvenBelow100OddBelow50>::new::<U98>().value()
+        );
+        assert!(Bounded::<u8, EvenBelow100OddBelow50>::all()
+            .map(Bounded::value)
+            .eq((0..100).filter(|x| x % 2 == 0 || *x < 50)));
+        assert_eq!(Some(3), If::<Lt<Arg, Z0>, Neg<Arg>, Arg>::call(-3i8).get());
+        assert_eq!(Some(3), If::<Lt<Arg, Z0>, Neg<Arg>, Arg>::call(3i8).get());
+        assert_eq!(
+            Some(128),
+            If::<Lt<Arg, Z0>, Neg<Arg>, Arg>::call(-128i8).get()
+        );
+    }
+
+    #[test]
+    fn if_exhaustive() {
+        check_exhaustive::<u8, EvenBelow100OddBelow50>();
+        check_exhaustive::<i8, If<Lt<Arg, Z0>, Gt<Arg, N10>, Lt<Arg, P20>>>();
+        check_exhaustive::<i8, If<Ge<Arg, Z0>, Le<Arg, P5>, Eq<Rem<Arg, P3>, Z0>>>();
+        crate::check::check_contains::<u8, If<Eq<Arg, U0>, B1, Eq<Rem<U100, Arg>, U0>>>();
+        crate::check::check_contains_below::<i16, If<B0, B1, Ne<Arg, N7>>, U8>();
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_exhaustive::<u8, And<Ge<Arg, U3>, Eq<Rem<Arg, U4>, U1>>>();
+        check_exhaustive::<i8, Or<Lt<Arg, N100>, Gt<Arg, P100>>>();
+        crate::check::check_contains::<u8, Divides100>();
+        crate::check::check_contains::<u8, Xor<Lt<Arg, U9>, Gt<Arg, U3>>>();
+        crate::check::check_contains::<i8, Implies<Ne<Arg, Z0>, Eq<Rem<P100, Arg>, Z0>>>();
+    }
+}
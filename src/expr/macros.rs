@@ -1,3 +1,61 @@
+/// Defines an expression node applying a unary operator to an operand.
+///
+/// The node is a struct `$name<S>` in a private module `$mod_name`, which is re-exported.
+/// `ValueExpr` calls `$val_func` of `$val_trait`, a trait with an `Output`
+/// implemented for runtime values, and `TypeExpr` takes the `Output` of `$type_operator`,
+/// a trait implemented for `typenum` constants.
+/// Both levels must agree for every constant, which `check::check_contains` verifies
+/// for 8-bit types.
+///
+/// The node is an `OpaqueOperand`, and as a bound it infers the whole type as its `Interval`,
+/// so that `Bounded::all` scans every value of the argument's type.
+/// A node written by hand needs the same through `impl_opaque_node!`.
+///
+/// ```
+/// use boundnum::{define_unary_operator, expr::*, typenum::*, Bounded};
+///
+/// pub trait IsEvenExt {
+///     type Output;
+///     fn is_even(self) -> Self::Output;
+/// }
+///
+/// impl IsEvenExt for u8 {
+///     type Output = bool;
+///     fn is_even(self) -> bool {
+///         self % 2 == 0
+///     }
+/// }
+///
+/// pub trait IsEvenOp {
+///     type Output;
+/// }
+///
+/// impl IsEvenOp for UTerm {
+///     type Output = B1;
+/// }
+///
+/// impl<U: Unsigned, B: Bit + core::ops::Not> IsEvenOp for UInt<U, B> {
+///     type Output = <B as core::ops::Not>::Output;
+/// }
+///
+/// define_unary_operator! {
+///     is_even,
+///     /// Represents whether a value is even
+///     IsEven,
+///     IsEvenExt,
+///     IsEvenExt::is_even,
+///     IsEvenOp,
+/// }
+///
+/// type Even = BitAnd<IsEven<Arg>, Lt<Arg, U100>>;
+///
+/// fn main() {
+///     assert_eq!(42, Bounded::<u8, Even>::new::<U42>().value());
+///     assert_eq!(50, Bounded::<u8, Even>::all().count());
+///     boundnum::check::check_contains::<u8, Even>();
+/// }
+/// ```
+#[macro_export]
 macro_rules! define_unary_operator {
     (
         $mod_name:ident,
@@ -6,10 +64,57 @@ macro_rules! define_unary_operator {
         $val_trait:path,
         $val_func:path,
         $type_operator:path,
+    ) => {
+        $crate::define_unary_operator! {
+            @node
+            $mod_name,
+            $(#[$meta])*
+            $name,
+            $val_trait,
+            $val_func,
+            $type_operator,
+        }
+        $crate::define_unary_operator! { @operand $name<S> }
+        $crate::define_unary_operator! { @bound $name<S> }
+    };
+    ( @operand $name:ident $(< $($p:ident),+ >)? ) => {
+        impl $(< $($p),+ >)? $crate::expr::Operand for $name $(< $($p),+ >)? {
+            type Kind = $crate::expr::OpaqueOperand;
+        }
+    };
+    ( @bound $name:ident $(< $($p:ident),+ >)? ) => {
+        impl<T: $crate::value::TypeBound $(, $($p),+)? > $crate::expr::Interval<T>
+            for $name $(< $($p),+ >)?
+        {
+            type Min = T::Min;
+            type Max = T::Max;
+        }
+
+        impl<T: $crate::value::TypeBound $(, $($p),+)? > $crate::expr::Stride<T>
+            for $name $(< $($p),+ >)?
+        {
+            type Step = $crate::typenum::U1;
+            type Residue = T::Min;
+        }
+
+        impl<T $(, $($p),+)? > $crate::expr::IsExact<T> for $name $(< $($p),+ >)? {
+            type Output = $crate::typenum::B0;
+        }
+    };
+    (
+        @node
+        $mod_name:ident,
+        $(#[$meta:meta])*
+            $name:ident,
+        $val_trait:path,
+        $val_func:path,
+        $type_operator:path,
     ) => {
         pub use $mod_name::*;
         mod $mod_name {
+            #[allow(unused_imports)]
             use super::*;
+            use $crate::expr::{Call, TypeExpr, ValType, ValueExpr};
             use $val_trait as ValTrait;
             use $type_operator as TypeOperator;
             use core::marker::PhantomData;
@@ -39,9 +144,84 @@ macro_rules! define_unary_operator {
     };
 }
 
+/// Defines an expression node applying a binary operator to two operands.
+///
+/// The node is a struct `$name<L, R>`, defined like the one of `define_unary_operator!`,
+/// except that `$val_trait` and `$type_operator` take the right operand as a parameter.
+///
+/// ```
+/// use boundnum::{
+///     define_binary_operator,
+///     expr::{Arg, Le},
+///     typenum::{consts::*, Diff, Max, Maximum, Min, Minimum},
+///     Bounded,
+/// };
+///
+/// pub trait AbsDiffExt<R> {
+///     type Output;
+///     fn abs_diff(self, rhs: R) -> Self::Output;
+/// }
+///
+/// impl AbsDiffExt<u8> for u8 {
+///     type Output = u8;
+///     fn abs_diff(self, rhs: u8) -> u8 {
+///         u8::abs_diff(self, rhs)
+///     }
+/// }
+///
+/// pub trait AbsDiffOp<R> {
+///     type Output;
+/// }
+///
+/// impl<L, R> AbsDiffOp<R> for L
+/// where
+///     L: Max<R> + Min<R>,
+///     Maximum<L, R>: core::ops::Sub<Minimum<L, R>>,
+/// {
+///     type Output = Diff<Maximum<L, R>, Minimum<L, R>>;
+/// }
+///
+/// define_binary_operator! {
+///     abs_diff,
+///     /// Represents `abs_diff` of `u8`
+///     AbsDiff,
+///     AbsDiffExt,
+///     AbsDiffExt::abs_diff,
+///     AbsDiffOp,
+/// }
+///
+/// type Near100 = Le<AbsDiff<Arg, U100>, U5>;
+///
+/// fn main() {
+///     assert_eq!(97, Bounded::<u8, Near100>::new::<U97>().value());
+///     boundnum::check::check_contains::<u8, Near100>();
+/// }
+/// ```
+#[macro_export]
 macro_rules! define_binary_operator {
     (
-        $mod_name: ident,
+        $mod_name:ident,
+        $(#[$meta:meta])*
+            $name:ident,
+        $val_trait:path,
+        $val_func:path,
+        $type_operator:path,
+    ) => {
+        $crate::define_binary_operator! {
+            @node
+            $mod_name,
+            $(#[$meta])*
+            $name,
+            $val_trait,
+            $val_func,
+            $type_operator,
+        }
+        $crate::define_unary_operator! { @operand $name<L, R> }
+        $crate::define_unary_operator! { @bound $name<L, R> }
+    };
+    (
+        @node
+        $mod_name:ident,
         $(#[$meta:meta])*
             $name:ident,
         $val_trait:path,
@@ -50,7 +230,9 @@ macro_rules! define_binary_operator {
     ) => {
         pub use $mod_name::*;
         mod $mod_name {
+            #[allow(unused_imports)]
             use super::*;
+            use $crate::expr::{Call, TypeExpr, ValType, ValueExpr};
             use $type_operator as TypeOperator;
             use $val_trait as ValTrait;
             use core::marker::PhantomData;
@@ -87,3 +269,38 @@ macro_rules! define_binary_operator {
         }
     };
 }
+
+/// Implements `Operand`, `Interval`, `Stride` and `IsExact` for an expression node
+/// written by hand, as `define_unary_operator!` does for its nodes.
+///
+/// `Boundable::bound` on primitive integers requires them of every node of the bound,
+/// to reject a bound which never holds. The node infers the whole type as its `Interval`.
+///
+/// ```
+/// use boundnum::{expr::*, impl_opaque_node, typenum::consts::*, Boundable};
+///
+/// /// Holds for even values.
+/// #[derive(Debug, Default, Clone, Copy)]
+/// pub struct IsEven;
+///
+/// impl ValueExpr<u8> for IsEven {
+///     type Output = bool;
+///     fn call(arg: u8) -> bool {
+///         arg % 2 == 0
+///     }
+/// }
+///
+/// impl_opaque_node!(IsEven);
+///
+/// fn main() {
+///     assert!(Boundable::<IsEven>::bound(4u8).is_some());
+///     assert!(Boundable::<BitAnd<IsEven, Lt<Arg, U3>>>::bound(3u8).is_none());
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_opaque_node {
+    ( $name:ident $(< $($p:ident),+ >)? ) => {
+        $crate::define_unary_operator! { @operand $name $(< $($p),+ >)? }
+        $crate::define_unary_operator! { @bound $name $(< $($p),+ >)? }
+    };
+}
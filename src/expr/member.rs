@@ -0,0 +1,254 @@
+use super::*;
+use crate::typenum::{ATerm, TArr, B0, B1};
+use crate::value::ToI128;
+
+mod helper_traits {
+    use super::*;
+
+    /// Folds a list `tarr![V, W, ...]` into `Or<Eq<Arg, V>, Or<Eq<Arg, W>, ...>>`.
+    pub trait AnyEq {
+        type Output;
+    }
+
+    impl AnyEq for ATerm {
+        type Output = B0;
+    }
+
+    impl<V> AnyEq for TArr<V, ATerm> {
+        type Output = Eq<Arg, V>;
+    }
+
+    impl<V, W, Rest> AnyEq for TArr<V, TArr<W, Rest>>
+    where
+        TArr<W, Rest>: AnyEq,
+    {
+        type Output = Or<Eq<Arg, V>, <TArr<W, Rest> as AnyEq>::Output>;
+    }
+
+    /// Folds a list `tarr![V, W, ...]` into `And<Ne<Arg, V>, And<Ne<Arg, W>, ...>>`.
+    pub trait AllNe {
+        type Output;
+    }
+
+    impl AllNe for ATerm {
+        type Output = B1;
+    }
+
+    impl<V> AllNe for TArr<V, ATerm> {
+        type Output = Ne<Arg, V>;
+    }
+
+    impl<V, W, Rest> AllNe for TArr<V, TArr<W, Rest>>
+    where
+        TArr<W, Rest>: AllNe,
+    {
+        type Output = And<Ne<Arg, V>, <TArr<W, Rest> as AllNe>::Output>;
+    }
+
+    /// Seeks the constants of a list in ascending order, skipping duplicates.
+    pub trait Seek {
+        /// The least constant from `value` on.
+        fn next_from(value: i128) -> Option<i128>;
+        /// The greatest constant up to `value`.
+        fn prev_upto(value: i128) -> Option<i128>;
+    }
+
+    impl Seek for ATerm {
+        fn next_from(_: i128) -> Option<i128> {
+            None
+        }
+
+        fn prev_upto(_: i128) -> Option<i128> {
+            None
+        }
+    }
+
+    impl<V: ToI128, Rest: Seek> Seek for TArr<V, Rest> {
+        fn next_from(value: i128) -> Option<i128> {
+            let rest = Rest::next_from(value);
+            if V::I128 < value {
+                rest
+            } else {
+                Some(rest.map_or(V::I128, |rest| rest.min(V::I128)))
+            }
+        }
+
+        fn prev_upto(value: i128) -> Option<i128> {
+            let rest = Rest::prev_upto(value);
+            if V::I128 > value {
+                rest
+            } else {
+                Some(rest.map_or(V::I128, |rest| rest.max(V::I128)))
+            }
+        }
+    }
+}
+
+macro_rules! define_membership {
+    ( $(#[$meta:meta])* $name:ident, $fold:ident ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy)]
+        pub struct $name<List>(core::marker::PhantomData<List>);
+
+        impl<A, List> ValueExpr<A> for $name<List>
+        where
+            List: helper_traits::$fold,
+            <List as helper_traits::$fold>::Output: ValueExpr<A, Output = bool>,
+        {
+            type Output = bool;
+
+            fn call(arg: A) -> Self::Output {
+                <List as helper_traits::$fold>::Output::call(arg)
+            }
+        }
+
+        impl<A, List> TypeExpr<A> for $name<List>
+        where
+            List: helper_traits::$fold,
+            <List as helper_traits::$fold>::Output: TypeExpr<A>,
+        {
+            type Output = Call<<List as helper_traits::$fold>::Output, A>;
+        }
+
+        impl<T, List> Stride<T> for $name<List>
+        where
+            List: helper_traits::$fold,
+            <List as helper_traits::$fold>::Output: Stride<T>,
+        {
+            type Step = StepOf<<List as helper_traits::$fold>::Output, T>;
+            type Residue = ResidueOf<<List as helper_traits::$fold>::Output, T>;
+        }
+
+        impl<T, List> IsExact<T> for $name<List>
+        where
+            List: helper_traits::$fold,
+            <List as helper_traits::$fold>::Output: IsExact<T>,
+        {
+            type Output = ExactOf<<List as helper_traits::$fold>::Output, T>;
+        }
+    };
+}
+
+define_membership! {
+    /// Represents `Arg` being equal to one of the constants in a `typenum::tarr!` list.
+    ///
+    /// `Bounded::all` yields exactly the listed values in ascending order,
+    /// skipping the values between them.
+    ///
+    /// ```
+    /// use boundnum::{expr::*, typenum::{consts::*, tarr}, Bounded};
+    ///
+    /// type BlockSize = Bounded<u32, OneOf<tarr![U16, U1, U2, U4, U8]>>;
+    ///
+    /// assert_eq!(4, BlockSize::new::<U4>().value());
+    /// assert!(BlockSize::all().map(Bounded::value).eq([1, 2, 4, 8, 16]));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use boundnum::{expr::*, typenum::{consts::*, tarr}, Bounded};
+    ///
+    /// let block_size = Bounded::<u32, OneOf<tarr![U1, U2, U4, U8, U16]>>::new::<U3>();
+    /// ```
+    OneOf,
+    AnyEq
+}
+
+define_membership! {
+    /// Represents `Arg` being equal to none of the constants in a `typenum::tarr!` list.
+    NoneOf,
+    AllNe
+}
+
+/// Seeks the listed values, so that `Bounded::all` skips the values between them.
+impl<T, List> Interval<T> for OneOf<List>
+where
+    List: helper_traits::AnyEq + helper_traits::Seek,
+    <List as helper_traits::AnyEq>::Output: Interval<T>,
+{
+    type Min = MinOf<<List as helper_traits::AnyEq>::Output, T>;
+    type Max = MaxOf<<List as helper_traits::AnyEq>::Output, T>;
+
+    fn next_candidate(value: i128) -> Option<i128> {
+        <List as helper_traits::Seek>::next_from(value)
+    }
+
+    fn prev_candidate(value: i128) -> Option<i128> {
+        <List as helper_traits::Seek>::prev_upto(value)
+    }
+}
+
+impl<T, List> Interval<T> for NoneOf<List>
+where
+    List: helper_traits::AllNe,
+    <List as helper_traits::AllNe>::Output: Interval<T>,
+{
+    type Min = MinOf<<List as helper_traits::AllNe>::Output, T>;
+    type Max = MaxOf<<List as helper_traits::AllNe>::Output, T>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check::{check_contains, check_exhaustive, check_exhaustive_below},
+        typenum::{consts::*, tarr},
+        Boundable, Bounded,
+    };
+    use impls::impls;
+
+    #[test]
+    fn value_level() {
+        type Sizes = OneOf<tarr![U1, U2, U4, U8, U16]>;
+        assert!(Boundable::<Sizes>::bound(8u32).is_some());
+        assert!(Boundable::<Sizes>::bound(3u32).is_none());
+        assert!(Boundable::<NoneOf<tarr![P3, N3]>>::bound(3i64).is_none());
+        assert!(Boundable::<NoneOf<tarr![P3, N3]>>::bound(0i64).is_some());
+        assert!(Boundable::<OneOf<tarr![]>>::bound(0u8).is_none());
+        assert!(Boundable::<NoneOf<tarr![]>>::bound(0u8).is_some());
+    }
+
+    #[test]
+    fn type_level() {
+        assert!(impls!(OneOf<tarr![U1, U2, U4]>: Contains<U4, Output = B1>));
+        assert!(impls!(OneOf<tarr![U1, U2, U4]>: Contains<U3, Output = B0>));
+        assert!(impls!(NoneOf<tarr![N1, P1]>: Contains<Z0, Output = B1>));
+        assert!(impls!(NoneOf<tarr![N1, P1]>: Contains<N1, Output = B0>));
+        assert!(impls!(OneOf<tarr![U1]>: Exact<u8>));
+        assert!(impls!(NoneOf<tarr![]>: crate::expr::Tautology<u8>));
+        assert!(impls!(Bounded<u8, NoneOf<tarr![]>>: From<u8>));
+    }
+
+    #[test]
+    fn all() {
+        type Listed = OneOf<tarr![P5, N100, Z0, P5, P127]>;
+        assert!(Bounded::<i8, Listed>::all()
+            .map(Bounded::value)
+            .eq([-100, 0, 5, 127]));
+        assert!(Bounded::<u8, NoneOf<tarr![U0, U255]>>::all()
+            .map(Bounded::value)
+            .eq(1..=254));
+
+        type Far = OneOf<tarr![U4294967295, U1, U4294967295]>;
+        assert!(Bounded::<u32, Far>::all()
+            .map(Bounded::value)
+            .eq([1, u32::MAX]));
+        assert!(Bounded::<u32, Far>::all()
+            .rev()
+            .map(Bounded::value)
+            .eq([u32::MAX, 1]));
+        let mut listed = Bounded::<i8, Listed>::all();
+        assert_eq!(Some(-100), listed.next().map(Bounded::value));
+        assert_eq!(Some(127), listed.next_back().map(Bounded::value));
+        assert!(listed.map(Bounded::value).eq([0, 5]));
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_exhaustive::<u8, OneOf<tarr![U3, U1, U200, U3]>>();
+        check_exhaustive::<i8, OneOf<tarr![N128, P127, Z0]>>();
+        check_exhaustive::<u8, NoneOf<tarr![U0, U9, U255]>>();
+        check_exhaustive_below::<i16, NoneOf<tarr![N7, P7]>, U8>();
+        check_contains::<u8, OneOf<tarr![N1, U2, P300]>>();
+        check_contains::<i8, NoneOf<tarr![U200, P1, N129]>>();
+    }
+}
@@ -1,8 +1,126 @@
-// use super::*;
+use crate::typenum::{ATerm, TArr};
+
+mod helper_traits {
+    use super::*;
+
+    pub trait LenExt {
+        type Output;
+        fn len(self) -> Self::Output;
+    }
+
+    impl LenExt for &str {
+        type Output = usize;
+        fn len(self) -> Self::Output {
+            str::len(self)
+        }
+    }
+
+    impl<T> LenExt for &[T] {
+        type Output = usize;
+        fn len(self) -> Self::Output {
+            <[T]>::len(self)
+        }
+    }
+
+    /// `typenum` counterpart of `len` for `typenum::tarr!` lists.
+    pub trait LenOp {
+        type Output;
+    }
+
+    impl LenOp for ATerm {
+        type Output = crate::typenum::Length<ATerm>;
+    }
+
+    impl<V, A> LenOp for TArr<V, A>
+    where
+        TArr<V, A>: crate::typenum::Len,
+    {
+        type Output = crate::typenum::Length<TArr<V, A>>;
+    }
+}
+
+use helper_traits::*;
+
+define_unary_operator! {
+    len,
+    /// Represents `len` of strings and slices, which counts bytes of strings
+    ///
+    /// ```
+    /// use boundnum::{expr::*, typenum::consts::*, Boundable, Bounded};
+    ///
+    /// type Name<'a> = Bounded<&'a str, BitAnd<Ne<Len<Arg>, U0>, Le<Len<Arg>, U8>>>;
+    ///
+    /// let name: Name = "boundnum".bound().unwrap();
+    /// assert_eq!("boundnum", name.value());
+    /// assert_eq!(8, name.len());
+    ///
+    /// let too_long: Option<Name> = "bounded number".bound();
+    /// assert!(too_long.is_none());
+    /// ```
+    Len,
+    LenExt,
+    LenExt::len,
+    LenOp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expr::{Arg, Contains, Le, Ne, TypeExpr},
+        typenum::{consts::*, tarr, B0, B1},
+        Boundable,
+    };
+    use impls::impls;
+
+    type Short = Le<Len<Arg>, U4>;
+
+    #[test]
+    fn borrowed() {
+        assert!(Boundable::<Short>::bound("four").is_some());
+        assert!(Boundable::<Short>::bound("three").is_none());
+        assert!(Boundable::<Short>::bound("äö").is_some());
+        assert!(Boundable::<Short>::bound("äöü").is_none());
+        assert!(Boundable::<Short>::bound(&[0u64; 4][..]).is_some());
+        assert!(Boundable::<Ne<Len<Arg>, U0>>::bound(&[0u64; 0][..]).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn owned() {
+        use crate::{
+            expr::{BitAnd, ForAll, Lt},
+            Bounded,
+        };
+        use alloc::{string::String, vec, vec::Vec};
+
+        let word: Bounded<String, Short> = String::from("word").bound().unwrap();
+        assert_eq!(4, word.len());
+        assert_eq!("word", word.value());
+        assert!(Boundable::<Short>::bound(String::from("words")).is_none());
+
+        type Digits = BitAnd<Le<Len<Arg>, U4>, ForAll<Lt<Arg, U10>>>;
+        let digits: Bounded<Vec<u8>, Digits> = vec![1, 2, 3].bound().unwrap();
+        assert_eq!(&[1, 2, 3], &digits[..]);
+        assert!(Boundable::<Digits>::bound(vec![1u8, 2, 3, 4, 5]).is_none());
+        assert!(Boundable::<Digits>::bound(vec![1u8, 20]).is_none());
+
+        let all: Bounded<Vec<u8>, ForAll<Lt<Arg, U10>>> = vec![7, 8].bound().unwrap();
+        assert!(all.iter().map(Bounded::value).eq([7, 8]));
+    }
+
+    #[test]
+    fn type_level() {
+        assert!(impls!(Len<Arg>: TypeExpr<tarr![U1, U2, U3], Output = U3>));
+        assert!(impls!(Len<Arg>: TypeExpr<tarr![], Output = U0>));
+        assert!(impls!(Short: Contains<tarr![U1, U2, U3, U4], Output = B1>));
+        assert!(impls!(Short: Contains<tarr![U1, U2, U3, U4, U5], Output = B0>));
+        assert!(impls!(Len<Arg>: !TypeExpr<U5>));
+    }
+}
 
 // Abs
 // Gcd
-// Len
 // Logarithm2
 // PartialDiv
 // Pow
@@ -0,0 +1,190 @@
+use super::*;
+use crate::typenum::{ATerm, TArr, B0, B1};
+
+/// Collections whose elements a quantifier evaluates a bound over.
+pub trait Elements {
+    type Item: Copy;
+    fn elements(&self) -> &[Self::Item];
+}
+
+impl<T: Copy, const N: usize> Elements for [T; N] {
+    type Item = T;
+    fn elements(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Copy> Elements for &[T] {
+    type Item = T;
+    fn elements(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Copy> Elements for alloc::vec::Vec<T> {
+    type Item = T;
+    fn elements(&self) -> &[T] {
+        self
+    }
+}
+
+mod helper_traits {
+    use super::*;
+
+    /// Evaluates `B` over every constant of a `typenum::tarr!` list.
+    pub trait AllOf<B> {
+        type Output;
+    }
+
+    impl<B> AllOf<B> for ATerm {
+        type Output = B1;
+    }
+
+    impl<B, V, Rest> AllOf<B> for TArr<V, Rest>
+    where
+        B: TypeExpr<V>,
+        Rest: AllOf<B>,
+        Call<B, V>: core::ops::BitAnd<<Rest as AllOf<B>>::Output>,
+    {
+        type Output = <Call<B, V> as core::ops::BitAnd<<Rest as AllOf<B>>::Output>>::Output;
+    }
+
+    /// Evaluates `B` over some constant of a `typenum::tarr!` list.
+    pub trait AnyOf<B> {
+        type Output;
+    }
+
+    impl<B> AnyOf<B> for ATerm {
+        type Output = B0;
+    }
+
+    impl<B, V, Rest> AnyOf<B> for TArr<V, Rest>
+    where
+        B: TypeExpr<V>,
+        Rest: AnyOf<B>,
+        Call<B, V>: core::ops::BitOr<<Rest as AnyOf<B>>::Output>,
+    {
+        type Output = <Call<B, V> as core::ops::BitOr<<Rest as AnyOf<B>>::Output>>::Output;
+    }
+}
+
+macro_rules! define_quantifier {
+    ( $(#[$meta:meta])* $name:ident, $method:ident, $fold:ident ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy)]
+        pub struct $name<B>(core::marker::PhantomData<B>);
+
+        impl<A, B> ValueExpr<A> for $name<B>
+        where
+            A: Elements,
+            B: AsBound<A::Item>,
+        {
+            type Output = bool;
+
+            fn call(arg: A) -> Self::Output {
+                arg.elements().iter().$method(|element| B::contains(element))
+            }
+        }
+
+        /// Lists of constants are `typenum::tarr!`s.
+        impl<A, B> TypeExpr<A> for $name<B>
+        where
+            A: helper_traits::$fold<B>,
+        {
+            type Output = <A as helper_traits::$fold<B>>::Output;
+        }
+    };
+}
+
+define_quantifier! {
+    /// Represents that every element of an array or a slice satisfies `B`.
+    ///
+    /// ```
+    /// use boundnum::{expr::*, typenum::consts::*, Boundable, Bounded};
+    ///
+    /// type Digits = Bounded<[u8; 4], ForAll<Lt<Arg, U10>>>;
+    ///
+    /// let digits: Digits = [1, 9, 8, 4].bound().unwrap();
+    /// let digit: Bounded<u8, Lt<Arg, U10>> = digits.get(1).unwrap();
+    /// assert_eq!(9, *digit);
+    /// assert_eq!(22, digits.iter().map(Bounded::value).sum::<u8>());
+    ///
+    /// let not_digits: Option<Digits> = [1, 9, 8, 40].bound();
+    /// assert!(not_digits.is_none());
+    /// ```
+    ForAll,
+    all,
+    AllOf
+}
+
+define_quantifier! {
+    /// Represents that some element of an array or a slice satisfies `B`.
+    ///
+    /// ```
+    /// use boundnum::{expr::*, typenum::consts::*, Boundable, Bounded};
+    ///
+    /// let values: &[i32] = &[3, 0, 5];
+    /// let with_zero: Bounded<&[i32], Any<Eq<Arg, Z0>>> = values.bound().unwrap();
+    /// assert_eq!(0, *with_zero.witness());
+    /// ```
+    Any,
+    any,
+    AnyOf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        typenum::{consts::*, tarr},
+        Boundable, Bounded,
+    };
+    use impls::impls;
+
+    type Digit = Lt<Arg, U10>;
+
+    #[test]
+    fn arrays() {
+        assert!(Boundable::<ForAll<Digit>>::bound([0u8; 16]).is_some());
+        assert!(Boundable::<ForAll<Digit>>::bound([0u8, 10]).is_none());
+        assert!(Boundable::<ForAll<Digit>>::bound([0u8; 0]).is_some());
+        assert!(Boundable::<Any<Digit>>::bound([10u8, 9]).is_some());
+        assert!(Boundable::<Any<Digit>>::bound([10u8, 11]).is_none());
+        assert!(Boundable::<Any<Digit>>::bound([0u8; 0]).is_none());
+    }
+
+    #[test]
+    fn slices() {
+        let values = [5i16, -3, 0, 7];
+        assert!(Boundable::<ForAll<Ge<Arg, N3>>>::bound(&values[..]).is_some());
+        assert!(Boundable::<ForAll<Ge<Arg, Z0>>>::bound(&values[..]).is_none());
+        assert!(Boundable::<ForAll<Ge<Arg, Z0>>>::bound(&values[2..]).is_some());
+        assert!(Boundable::<Any<Lt<Arg, Z0>>>::bound(&values[..]).is_some());
+    }
+
+    #[test]
+    fn type_level() {
+        assert!(impls!(ForAll<Digit>: Contains<tarr![U1, U9], Output = B1>));
+        assert!(impls!(ForAll<Digit>: Contains<tarr![U1, U10], Output = B0>));
+        assert!(impls!(ForAll<Digit>: Contains<tarr![], Output = B1>));
+        assert!(impls!(Any<Digit>: Contains<tarr![U10, U9], Output = B1>));
+        assert!(impls!(Any<Digit>: Contains<tarr![], Output = B0>));
+    }
+
+    #[test]
+    fn accessors() {
+        let digits: Bounded<[u8; 3], ForAll<Digit>> = [3, 1, 4].bound().unwrap();
+        let first: Option<Bounded<u8, Digit>> = digits.iter().next();
+        assert_eq!(Some(3), first.map(Bounded::value));
+        assert!(digits.iter().map(Bounded::value).eq([3, 1, 4]));
+        assert_eq!(Some(4), digits.get(2).map(Bounded::value));
+        assert_eq!(None, digits.get(3).map(Bounded::value));
+        assert_eq!(3, digits.iter().len());
+        assert_eq!(Some(4), digits.iter().next_back().map(Bounded::value));
+
+        let values = [12u8, 15, 4, 7];
+        let some: Bounded<&[u8], Any<Digit>> = (&values[..]).bound().unwrap();
+        assert_eq!(4, some.witness().value());
+    }
+}
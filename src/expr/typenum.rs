@@ -1,20 +1,21 @@
 use super::{TypeExpr, ValueExpr};
 use crate::typenum::*;
-use crate::value::ToValue;
+use crate::value::{ToConst, ToValue};
 
 macro_rules! impl_expr_for_typenum {
     ( $name:ident $(< $($p:ident),+ >)? $(
         where
             $($pb:ident: $first_bound:ident $(+ $rest_bound:ident)* ),+
     )?) => {
+        /// Out of range constants evaluate exactly as `Wide`.
         impl<A $(, $($p),+)? > ValueExpr<A> for $name $(< $($p),+ >)?
             where
-                Self: ToValue<A>, $(
+                Self: ToConst<A>, $(
                 $($pb: $first_bound $(+ $rest_bound)* ),+
         )? {
-            type Output = A;
+            type Output = <Self as ToConst<A>>::Output;
             fn call(_: A) -> Self::Output {
-                Self::VALUE
+                Self::CONST
             }
         }
 
@@ -51,3 +52,56 @@ macro_rules! impl_expr_for_bit {
 
 impl_expr_for_bit! { B0 }
 impl_expr_for_bit! { B1 }
+
+macro_rules! impl_expr_for_ordering {
+    ( $name:ident ) => {
+        impl<A> ValueExpr<A> for $name {
+            type Output = core::cmp::Ordering;
+            fn call(_: A) -> Self::Output {
+                <Self as ToValue<core::cmp::Ordering>>::VALUE
+            }
+        }
+
+        impl<A> TypeExpr<A> for $name {
+            type Output = Self;
+        }
+    };
+}
+
+impl_expr_for_ordering! { Less }
+impl_expr_for_ordering! { Equal }
+impl_expr_for_ordering! { Greater }
+
+#[cfg(test)]
+mod tests {
+    use crate::expr::*;
+
+    #[test]
+    fn mixed_width_constants() {
+        use crate::check::{check_exhaustive, check_exhaustive_below};
+        use ::typenum::consts::*;
+        check_exhaustive::<u8, Gt<Arg, N1>>();
+        check_exhaustive::<u8, Le<Arg, P300>>();
+        check_exhaustive::<u8, BitAnd<Ge<Arg, N5>, Lt<Arg, P10>>>();
+        check_exhaustive::<u8, Eq<Arg, P7>>();
+        check_exhaustive::<u8, Ne<Arg, N7>>();
+        check_exhaustive::<i8, Lt<Arg, U0>>();
+        check_exhaustive::<i8, RangeInclusive<U0, U300>>();
+        check_exhaustive_below::<i16, BitAnd<Gt<Arg, U200>, Le<Arg, U256>>, U8>();
+        check_exhaustive_below::<u16, Range<N300, P300>, U8>();
+    }
+
+    #[test]
+    fn one_bound_for_several_widths() {
+        use crate::{typenum::consts::*, Bounded};
+        use impls::impls;
+        type NonNegative = Ge<Arg, Z0>;
+        assert!(impls!(Bounded<u8, NonNegative>: From<u8>));
+        assert!(impls!(Bounded<u64, NonNegative>: From<u64>));
+        assert!(impls!(Bounded<i8, NonNegative>: !From<i8>));
+        assert_eq!(5, Bounded::<i32, NonNegative>::new::<U5>().value());
+        assert_eq!(5, Bounded::<u32, NonNegative>::new::<P5>().value());
+        assert!(impls!(Lt<Arg, N1>: !NonEmpty<u16>));
+        assert!(impls!(Le<Arg, N1>: NonEmpty<i16>));
+    }
+}
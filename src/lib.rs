@@ -18,18 +18,34 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod bitfield;
 mod bounded_impls;
+pub mod check;
+pub mod codec;
 pub mod expr;
+pub mod map;
+pub mod ordinal;
+pub mod packed;
+pub mod set;
 pub mod value;
 
+pub use packed::Packed;
 pub use typenum;
 
 use core::{iter::FusedIterator, marker::PhantomData};
-use expr::{AsBound, Contains, Range, RangeFrom, RangeInclusive};
+use expr::{
+    AsBound, Contains, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive, Satisfiable,
+};
 use shrinkwraprs::Shrinkwrap;
 use value::ToValue;
 
 /// A wrapper struct representing bounded numeric type.
+///
+/// The value is stored as `T` itself, so `Option<Bounded<T, B>>` gains no niche
+/// even if `B` excludes zero. Convert it into `NonZero*` to store it in one.
 #[derive(Shrinkwrap, Copy, Clone, Debug)]
 pub struct Bounded<T, B: AsBound<T>> {
     #[shrinkwrap(main_field)]
@@ -54,12 +70,37 @@ impl<T, B: AsBound<T>> Bounded<T, B> {
     }
 }
 
+impl<T, B> Bounded<T, B>
+where
+    T: packed::Offset,
+    B: codec::Encodable<T>,
+{
+    /// Iterates every value satisfying the bound in ascending order.
+    ///
+    /// ```
+    /// use boundnum::{expr::*, typenum::consts::*, Bounded};
+    ///
+    /// type Odd = Bounded<u8, BitAnd<Lt<Arg, U10>, Eq<Rem<Arg, U2>, U1>>>;
+    /// assert!(Odd::all().map(Bounded::value).eq([1, 3, 5, 7, 9]));
+    /// assert_eq!(5, Odd::all().len());
+    /// ```
+    pub fn all() -> ordinal::All<T, B> {
+        ordinal::All::new()
+    }
+}
+
 impl<T, Start, End> Bounded<T, Range<Start, End>>
 where
-    Range<Start, End>: AsBound<T> + ToValue<core::ops::Range<T>>,
+    Range<Start, End>: AsBound<T> + expr::NonEmpty<T> + ToValue<core::ops::Range<T>>,
     core::ops::Range<T>: Iterator<Item = T> + DoubleEndedIterator + FusedIterator,
 {
-    pub fn iter() -> impl Iterator<Item = Self> + DoubleEndedIterator + FusedIterator {
+    /// Empty ranges are rejected.
+    /// ```compile_fail
+    /// use boundnum::{expr::*, typenum::consts::*, Bounded};
+    ///
+    /// Bounded::<u8, Range<U5, U3>>::iter();
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + FusedIterator {
         Range::<Start, End>::VALUE.map(|value| Bounded {
             value,
             bound: PhantomData,
@@ -69,10 +110,11 @@ where
 
 impl<T, Start, End> Bounded<T, RangeInclusive<Start, End>>
 where
-    RangeInclusive<Start, End>: AsBound<T> + ToValue<core::ops::RangeInclusive<T>>,
+    RangeInclusive<Start, End>:
+        AsBound<T> + expr::NonEmpty<T> + ToValue<core::ops::RangeInclusive<T>>,
     core::ops::RangeInclusive<T>: Iterator<Item = T> + DoubleEndedIterator + FusedIterator,
 {
-    pub fn iter() -> impl Iterator<Item = Self> + DoubleEndedIterator + FusedIterator {
+    pub fn iter() -> impl DoubleEndedIterator<Item = Self> + FusedIterator {
         RangeInclusive::<Start, End>::VALUE.map(|value| Bounded {
             value,
             bound: PhantomData,
@@ -80,19 +122,88 @@ where
     }
 }
 
+/// Stops at `T::MAX` instead of overflowing.
+///
+/// The iterators are `ExactSizeIterator`s unless the range has more than `usize::MAX` values.
 impl<T, Start> Bounded<T, RangeFrom<Start>>
 where
-    RangeFrom<Start>: AsBound<T> + ToValue<core::ops::RangeFrom<T>>,
-    core::ops::RangeFrom<T>: Iterator<Item = T> + FusedIterator,
+    T: packed::Offset,
+    RangeFrom<Start>: codec::Encodable<T> + expr::Exact<T>,
 {
-    pub fn iter() -> impl Iterator<Item = Self> + FusedIterator {
-        RangeFrom::<Start>::VALUE.map(|value| Bounded {
+    pub fn iter() -> ordinal::All<T, RangeFrom<Start>> {
+        Self::all()
+    }
+}
+
+/// Starts at `T::MIN`.
+impl<T, End> Bounded<T, RangeTo<End>>
+where
+    T: packed::Offset,
+    RangeTo<End>: codec::Encodable<T> + expr::Exact<T>,
+{
+    pub fn iter() -> ordinal::All<T, RangeTo<End>> {
+        Self::all()
+    }
+}
+
+/// Starts at `T::MIN`.
+impl<T, End> Bounded<T, RangeToInclusive<End>>
+where
+    T: packed::Offset,
+    RangeToInclusive<End>: codec::Encodable<T> + expr::Exact<T>,
+{
+    pub fn iter() -> ordinal::All<T, RangeToInclusive<End>> {
+        Self::all()
+    }
+}
+
+/// Every element satisfies `B`, so the accessors return them without checking.
+impl<C, B> Bounded<C, expr::ForAll<B>>
+where
+    C: expr::Elements,
+    B: AsBound<C::Item>,
+    expr::ForAll<B>: AsBound<C>,
+{
+    /// Returns `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Bounded<C::Item, B>> {
+        self.value.elements().get(index).map(|&value| Bounded {
+            value,
+            bound: PhantomData,
+        })
+    }
+
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = Bounded<C::Item, B>> + ExactSizeIterator + FusedIterator + '_
+    {
+        self.value.elements().iter().map(|&value| Bounded {
             value,
             bound: PhantomData,
         })
     }
 }
 
+impl<C, B> Bounded<C, expr::Any<B>>
+where
+    C: expr::Elements,
+    B: AsBound<C::Item>,
+    expr::Any<B>: AsBound<C>,
+{
+    /// Returns the first element satisfying `B`.
+    pub fn witness(&self) -> Bounded<C::Item, B> {
+        let value = *self
+            .value
+            .elements()
+            .iter()
+            .find(|&value| B::contains(value))
+            .expect("some element satisfies the bound");
+        Bounded {
+            value,
+            bound: PhantomData,
+        }
+    }
+}
+
 /// An easier way to define a const `Bounded` value.
 #[macro_export]
 macro_rules! CONST {
@@ -119,24 +230,48 @@ where
     };
 }
 
+/// Indexing by a `Bounded` index which is always in bounds, without bounds checks.
+///
+/// ```
+/// use boundnum::{expr::*, typenum::consts::*, Bounded, Boundable, GetBounded};
+///
+/// let mut table = [0u32; 24];
+/// let hour: Bounded<usize, Range<U0, U24>> = 13.bound().unwrap();
+/// table[hour] += 1;
+/// assert_eq!(1, *table.get_bounded(hour));
+/// ```
+pub trait GetBounded<B: AsBound<usize>> {
+    type Output: ?Sized;
+    fn get_bounded(&self, index: Bounded<usize, B>) -> &Self::Output;
+    fn get_bounded_mut(&mut self, index: Bounded<usize, B>) -> &mut Self::Output;
+}
+
 /// A trait of the type being converted to `Bounded`.
+///
+/// A bound on primitive integers which no value satisfies is rejected at compile time.
+///
+/// ```compile_fail
+/// use boundnum::{expr::*, typenum::consts::*, Boundable};
+///
+/// let never = Boundable::<Range<U5, U3>>::bound(4u8);
+/// ```
 pub trait Boundable<B> {
     type Raw;
     type Bound: AsBound<Self::Raw>;
     fn bound(self) -> Option<Bounded<Self::Raw, Self::Bound>>;
 }
 
+/// The value is checked by reference, so it need not be `Copy`.
 impl<T, B> Boundable<B> for T
 where
-    T: Copy,
-    B: AsBound<T>,
+    B: AsBound<T> + Satisfiable<T>,
 {
     type Raw = T;
     type Bound = B;
 
     /// Try to bound a value.
     fn bound(self) -> Option<Bounded<Self::Raw, Self::Bound>> {
-        if <Self::Bound as AsBound<T>>::contains(self) {
+        if <Self::Bound as AsBound<T>>::contains(&self) {
             Some(Bounded {
                 value: self,
                 bound: PhantomData,
@@ -186,4 +321,83 @@ mod tests {
             }());
         }
     }
+
+    #[test]
+    fn range_from_iter_to_max() {
+        use typenum::consts::*;
+        let iter = Bounded::<i8, RangeFrom<P120>>::iter();
+        assert_eq!(8, iter.len());
+        assert!(iter.map(Bounded::value).eq(120..=i8::MAX));
+        assert!(Bounded::<u64, RangeFrom<U0>>::iter()
+            .rev()
+            .map(Bounded::value)
+            .take(2)
+            .eq([u64::MAX, u64::MAX - 1]));
+    }
+
+    #[test]
+    fn range_from_iter_skip_len() {
+        use typenum::consts::*;
+        assert_eq!(245, Bounded::<u8, RangeFrom<U10>>::iter().skip(1).len());
+        assert_eq!(6, Bounded::<i8, RangeTo<N120>>::iter().skip(2).len());
+        assert_eq!(
+            0,
+            Bounded::<u16, RangeToInclusive<U0>>::iter().skip(3).len()
+        );
+
+        let full = Bounded::<u64, RangeFrom<U0>>::iter();
+        assert_eq!((usize::MAX, None), full.size_hint());
+        assert!(impls::impls!(ordinal::All<u64, RangeFrom<U0>>: !ExactSizeIterator));
+    }
+
+    #[test]
+    fn range_to_iter() {
+        use typenum::consts::*;
+        let iter = Bounded::<i8, RangeTo<N120>>::iter();
+        assert_eq!(8, iter.len());
+        assert!(iter.map(Bounded::value).eq(i8::MIN..-120));
+    }
+
+    #[test]
+    fn range_to_inclusive_iter() {
+        use typenum::consts::*;
+        let iter = Bounded::<i8, RangeToInclusive<N120>>::iter();
+        assert_eq!(9, iter.len());
+        assert!(iter.rev().map(Bounded::value).eq((i8::MIN..=-120).rev()));
+        assert!(Bounded::<u16, RangeToInclusive<U0>>::iter()
+            .map(Bounded::value)
+            .eq([0]));
+    }
+
+    #[test]
+    fn array_index() {
+        use expr::{Arg, BitAnd, Eq, Lt, Rem};
+        use typenum::consts::*;
+
+        let mut array = [0u8, 1, 2, 3];
+        assert_eq!(2, array[Bounded::<usize, Lt<Arg, U4>>::new::<U2>()]);
+        assert_eq!(
+            3,
+            array[Bounded::<usize, RangeInclusive<U1, U3>>::new::<U3>()]
+        );
+
+        type Even = BitAnd<Range<U0, U3>, Eq<Rem<Arg, U2>, U0>>;
+        let even: Bounded<usize, Even> = 2.bound().unwrap();
+        array[even] = 10;
+        *array.get_bounded_mut(even) += 1;
+        assert_eq!([0, 1, 11, 3], array);
+    }
+
+    #[test]
+    fn array_index_out_of_bounds() {
+        use core::ops::Index;
+        use expr::{Arg, Ne};
+        use impls::impls;
+        use typenum::consts::*;
+
+        assert!(impls!([u8; 4]: Index<Bounded<usize, Range<U0, U4>>>));
+        assert!(impls!([u8; 4]: !Index<Bounded<usize, Range<U0, U5>>>));
+        assert!(impls!([u8; 4]: !Index<Bounded<usize, Ne<Arg, U4>>>));
+        assert!(impls!([u8; 4]: !GetBounded<RangeFrom<U0>>));
+    }
 }
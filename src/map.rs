@@ -0,0 +1,272 @@
+//! A dense map keyed by `Bounded` values.
+
+use crate::{
+    codec::Encodable,
+    expr::{Cardinality, CardinalityOf, Exact},
+    ordinal::Ordinal,
+    packed::Offset,
+    typenum::Unsigned,
+    Bounded,
+};
+use core::{fmt, iter::FusedIterator};
+use generic_array::{ArrayLength, GenericArray};
+
+/// A type whose `Ordinal` positions are the dense indices `0..Size`.
+///
+/// ```
+/// use boundnum::{expr::*, map::Key, typenum::{consts::*, Unsigned}, Bounded};
+///
+/// type Fifth = Bounded<u8, BitAnd<Range<U0, U100>, Eq<Rem<Arg, U5>, U0>>>;
+///
+/// assert_eq!(20, <Fifth as Key>::Size::USIZE);
+/// ```
+pub trait Key: Ordinal {
+    /// The number of indices, `Ordinal::CARDINALITY` as `typenum::Unsigned`.
+    type Size: Unsigned;
+}
+
+/// Only `Exact` bounds are keys, so that every index holds a value.
+impl<T, B> Key for Bounded<T, B>
+where
+    T: Offset,
+    B: Encodable<T> + Exact<T> + Cardinality<T>,
+{
+    type Size = CardinalityOf<B, T>;
+}
+
+/// A map with one slot for each index of the `Key` `K`, sized at compile time.
+///
+/// ```
+/// use boundnum::{expr::*, map::BoundedMap, typenum::consts::*, Bounded, Boundable};
+///
+/// type Hour = Bounded<u8, Range<U0, U24>>;
+///
+/// let mut visits = BoundedMap::<Hour, u32>::new();
+/// visits.insert(Hour::new::<U9>(), 3);
+/// *visits.get_mut(Hour::new::<U9>()).unwrap() += 1;
+/// visits.insert(Hour::new::<U7>(), 1);
+///
+/// assert_eq!(Some(&4), visits.get(Hour::new::<U9>()));
+/// assert!(visits.iter().map(|(hour, _)| hour.value()).eq([7, 9]));
+/// ```
+pub struct BoundedMap<K, V>
+where
+    K: Key,
+    K::Size: ArrayLength,
+{
+    slots: GenericArray<Option<V>, K::Size>,
+    len: usize,
+}
+
+impl<K, V> BoundedMap<K, V>
+where
+    K: Key,
+    K::Size: ArrayLength,
+{
+    pub fn new() -> Self {
+        BoundedMap {
+            slots: GenericArray::default(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains_key(&self, key: K) -> bool {
+        self.slots[key.index() as usize].is_some()
+    }
+
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.slots[key.index() as usize].as_ref()
+    }
+
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        self.slots[key.index() as usize].as_mut()
+    }
+
+    /// Returns the previous value of `key`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let old = self.slots[key.index() as usize].replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        let old = self.slots[key.index() as usize].take();
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    /// Iterates entries in the order of the keys.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (K, &V)> + FusedIterator {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| Some((K::from_index(index as u128)?, slot.as_ref()?)))
+    }
+
+    /// Iterates entries in the order of the keys.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (K, &mut V)> + FusedIterator {
+        self.slots
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, slot)| Some((K::from_index(index as u128)?, slot.as_mut()?)))
+    }
+
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = K> + FusedIterator + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + FusedIterator {
+        self.slots.iter().filter_map(Option::as_ref)
+    }
+}
+
+impl<K, V> Default for BoundedMap<K, V>
+where
+    K: Key,
+    K::Size: ArrayLength,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Clone for BoundedMap<K, V>
+where
+    K: Key,
+    K::Size: ArrayLength,
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        BoundedMap {
+            slots: self.slots.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<K, V> fmt::Debug for BoundedMap<K, V>
+where
+    K: Key + fmt::Debug,
+    K::Size: ArrayLength,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V> core::iter::FromIterator<(K, V)> for BoundedMap<K, V>
+where
+    K: Key,
+    K::Size: ArrayLength,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V> Extend<(K, V)> for BoundedMap<K, V>
+where
+    K: Key,
+    K::Size: ArrayLength,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expr::{Arg, BitAnd, Eq, Ne, Range, RangeInclusive, Rem},
+        typenum::consts::*,
+        Boundable,
+    };
+    use impls::impls;
+
+    type Hour = Bounded<u8, Range<U0, U24>>;
+    type Offset = Bounded<i16, RangeInclusive<N3, P3>>;
+
+    #[test]
+    fn size() {
+        assert_eq!(24, <Hour as Key>::Size::USIZE);
+        assert_eq!(7, <Offset as Key>::Size::USIZE);
+        assert_eq!(
+            core::mem::size_of::<([Option<u8>; 7], usize)>(),
+            core::mem::size_of::<BoundedMap<Offset, u8>>()
+        );
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut map = BoundedMap::<Offset, &str>::new();
+        assert!(map.is_empty());
+        assert_eq!(None, map.insert(Offset::new::<P3>(), "three"));
+        assert_eq!(Some("three"), map.insert(Offset::new::<P3>(), "drei"));
+        assert_eq!(None, map.insert(Offset::new::<N3>(), "minus three"));
+        assert_eq!(2, map.len());
+        assert!(map.contains_key(Offset::new::<N3>()));
+        assert_eq!(Some("minus three"), map.remove(Offset::new::<N3>()));
+        assert_eq!(None, map.remove(Offset::new::<N3>()));
+        assert_eq!(1, map.len());
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn key_order() {
+        let map: BoundedMap<Offset, i16> = [2, -3, 0, 3]
+            .iter()
+            .map(|&value| (value.bound().unwrap(), value * 10))
+            .collect();
+        assert!(map.keys().map(Bounded::value).eq([-3, 0, 2, 3]));
+        assert!(map.values().rev().eq(&[30, 20, 0, -30]));
+    }
+
+    #[test]
+    fn strided_keys() {
+        type Fifth = Bounded<u8, BitAnd<Range<U0, U100>, Eq<Rem<Arg, U5>, U0>>>;
+        assert_eq!(20, <Fifth as Key>::Size::USIZE);
+        assert_eq!(
+            core::mem::size_of::<([Option<u8>; 20], usize)>(),
+            core::mem::size_of::<BoundedMap<Fifth, u8>>()
+        );
+
+        let map: BoundedMap<Fifth, u8> = [95, 0, 35]
+            .iter()
+            .map(|&value| (value.bound().unwrap(), value / 5))
+            .collect();
+        assert_eq!(7, Fifth::new::<U35>().index());
+        assert_eq!(Some(&7), map.get(Fifth::new::<U35>()));
+        assert_eq!(None, map.get(Fifth::new::<U40>()));
+        assert!(map.iter().map(|(key, &value)| (key.value(), value)).eq([
+            (0, 0),
+            (35, 7),
+            (95, 19)
+        ]));
+
+        assert!(impls!(Bounded<u8, Ne<Arg, U9>>: !Key));
+    }
+}
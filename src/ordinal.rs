@@ -0,0 +1,267 @@
+//! Positions of bounded values among all values satisfying their bound.
+
+use crate::{
+    codec::{Encodable, Encode},
+    expr::{Exact, Span, SpanOf},
+    packed::Offset,
+    typenum::{IsLess, B1},
+    value::TypeBound,
+    Bounded,
+};
+use core::{convert::TryFrom, iter::FusedIterator, marker::PhantomData};
+
+/// A type with finitely many values numbered from zero in ascending order.
+///
+/// ```
+/// use boundnum::{expr::*, ordinal::Ordinal, typenum::consts::*, Bounded};
+///
+/// type Fifth = Bounded<u8, BitAnd<Range<U0, U100>, Eq<Rem<Arg, U5>, U0>>>;
+///
+/// assert_eq!(20, Fifth::CARDINALITY);
+/// assert_eq!(3, Fifth::new::<U15>().index());
+/// assert_eq!(Some(Fifth::new::<U95>()), Fifth::from_index(19));
+/// assert_eq!(None, Fifth::from_index(20));
+/// ```
+pub trait Ordinal: Sized {
+    /// The number of values.
+    const CARDINALITY: u128;
+    /// Returns the number of values less than `self`.
+    fn index(self) -> u128;
+    /// Returns `None` if `index >= CARDINALITY`.
+    fn from_index(index: u128) -> Option<Self>;
+}
+
+/// Every encodable value of an `Exact` bound is valid, so the codes are the positions.
+impl<T, B> Ordinal for Bounded<T, B>
+where
+    T: Offset,
+    B: Encodable<T> + Exact<T>,
+{
+    const CARDINALITY: u128 = B::CARDINALITY;
+
+    #[inline]
+    fn index(self) -> u128 {
+        self.encode()
+    }
+
+    #[inline]
+    fn from_index(index: u128) -> Option<Self> {
+        Self::decode(index)
+    }
+}
+
+/// An iterator over every value of a bound in ascending order, returned by `Bounded::all`.
+///
+/// Scans the values of the bound's `Interval` congruent to its `Stride`,
+/// skipping those which do not satisfy the bound, and seeking past those which
+/// `Interval::next_candidate` rules out.
+/// The length is known if the bound is `Exact`,
+/// and it is an `ExactSizeIterator` if the span of the interval is also below `usize::MAX`,
+/// so that the length fits in `usize`.
+pub struct All<T, B> {
+    front: u128,
+    back: u128,
+    bound: PhantomData<(T, B)>,
+}
+
+impl<T, B> All<T, B>
+where
+    T: Offset,
+    B: Encodable<T>,
+{
+    pub(crate) fn new() -> Self {
+        All {
+            front: 0,
+            back: B::CARDINALITY,
+            bound: PhantomData,
+        }
+    }
+
+    fn remaining(&self) -> Option<usize> {
+        usize::try_from(self.back - self.front).ok()
+    }
+}
+
+impl<T, B> Iterator for All<T, B>
+where
+    T: Offset,
+    B: Encodable<T>,
+{
+    type Item = Bounded<T, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let code = B::seek(self.front);
+            if code >= self.back {
+                self.front = self.back;
+                break;
+            }
+            self.front = code + 1;
+            if let Some(bounded) = Bounded::decode(code) {
+                return Some(bounded);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        if B::EXACT {
+            (remaining.unwrap_or(usize::MAX), remaining)
+        } else {
+            (0, remaining)
+        }
+    }
+}
+
+impl<T, B> DoubleEndedIterator for All<T, B>
+where
+    T: Offset,
+    B: Encodable<T>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            match B::seek_back(self.back - 1) {
+                Some(code) if code >= self.front => {
+                    self.back = code;
+                    if let Some(bounded) = Bounded::decode(code) {
+                        return Some(bounded);
+                    }
+                }
+                _ => self.back = self.front,
+            }
+        }
+        None
+    }
+}
+
+impl<T, B> FusedIterator for All<T, B>
+where
+    T: Offset,
+    B: Encodable<T>,
+{
+}
+
+/// Every scanned value of an `Exact` bound is valid.
+impl<T, B> ExactSizeIterator for All<T, B>
+where
+    T: Offset,
+    B: Encodable<T> + Exact<T> + Span<T>,
+    SpanOf<B, T>: IsLess<<usize as TypeBound>::Max, Output = B1>,
+{
+}
+
+impl<T, B> Clone for All<T, B> {
+    fn clone(&self) -> Self {
+        All {
+            front: self.front,
+            back: self.back,
+            bound: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expr::{
+            Arg, AsBound, BitAnd, BitOr, Cmp, Div, Eq, Ge, Gt, IsPowerOfTwo, Le, Lt, Ne, Range,
+            RangeInclusive, Rem, RemEuclid,
+        },
+        typenum::consts::*,
+        Boundable,
+    };
+    use impls::impls;
+
+    #[test]
+    fn exact_bounds() {
+        assert!(impls!(Bounded<i8, RangeInclusive<N3, P3>>: Ordinal));
+        assert!(impls!(Bounded<u16, Eq<Rem<Arg, U7>, U3>>: Ordinal));
+        assert!(impls!(Bounded<u16, Eq<U3, Rem<Arg, U7>>>: Ordinal));
+        assert!(impls!(Bounded<i16, Eq<Rem<Arg, P7>, Z0>>: Ordinal));
+        assert!(impls!(Bounded<i16, Eq<RemEuclid<Arg, P7>, P3>>: Ordinal));
+        assert!(impls!(Bounded<u8, BitAnd<Ge<Arg, U9>, Eq<Rem<Arg, U4>, U1>>>: Ordinal));
+        assert!(impls!(Bounded<u8, Gt<U5, Arg>>: Ordinal));
+        assert!(impls!(Bounded<i8, Eq<Cmp<Arg, N3>, crate::typenum::Less>>: Ordinal));
+
+        assert!(impls!(Bounded<u8, Ne<Arg, U9>>: !Ordinal));
+        assert!(impls!(Bounded<u8, BitOr<Le<Arg, U1>, Ge<Arg, U9>>>: !Ordinal));
+        assert!(impls!(Bounded<u16, Eq<Rem<Arg, U7>, U9>>: !Ordinal));
+        assert!(impls!(Bounded<i16, Eq<Rem<Arg, P7>, P3>>: !Ordinal));
+        type TwoStrides = BitAnd<Eq<Rem<Arg, U2>, U0>, Eq<Rem<Arg, U3>, U0>>;
+        assert!(impls!(Bounded<u8, TwoStrides>: !Ordinal));
+        assert!(impls!(Bounded<u8, Lt<Div<Arg, U2>, U10>>: !Ordinal));
+    }
+
+    #[test]
+    fn roundtrip() {
+        type B = BitAnd<Range<U10, U50>, Eq<Rem<Arg, U3>, U2>>;
+        let values: std::vec::Vec<u32> = (0..100).filter(<B as AsBound<u32>>::contains).collect();
+        assert_eq!(values.len() as u128, Bounded::<u32, B>::CARDINALITY);
+        for (index, &value) in values.iter().enumerate() {
+            let bounded: Bounded<u32, B> = value.bound().unwrap();
+            assert_eq!(index as u128, bounded.index());
+            assert_eq!(Some(bounded), Ordinal::from_index(index as u128));
+        }
+        assert_eq!(None, Bounded::<u32, B>::from_index(values.len() as u128));
+    }
+
+    #[test]
+    fn all() {
+        type Strided = BitAnd<RangeInclusive<N20, P20>, Eq<Rem<Arg, P5>, Z0>>;
+        let all = Bounded::<i8, Strided>::all();
+        assert_eq!(9, all.len());
+        assert!(all.map(Bounded::value).eq((-20..=20).step_by(5)));
+
+        type Holes = BitOr<Le<Arg, U1>, BitAnd<Ge<Arg, U254>, Ne<Arg, U255>>>;
+        assert!(Bounded::<u8, Holes>::all()
+            .map(Bounded::value)
+            .eq([0, 1, 254]));
+        assert!(Bounded::<u8, Holes>::all()
+            .rev()
+            .map(Bounded::value)
+            .eq([254, 1, 0]));
+
+        let mut full = Bounded::<u64, Ge<Arg, U0>>::all();
+        assert_eq!((usize::MAX, None), full.size_hint());
+        assert!(impls!(All<u64, Ge<Arg, U0>>: !ExactSizeIterator));
+        assert_eq!(Some(u64::MAX), full.next_back().map(Bounded::value));
+        assert_eq!(Some(0), full.next().map(Bounded::value));
+    }
+
+    #[test]
+    fn all_without_inference() {
+        assert!(Bounded::<u8, IsPowerOfTwo<Arg>>::all()
+            .map(Bounded::value)
+            .eq((0..8).map(|shift| 1 << shift)));
+        assert!(Bounded::<u8, Lt<Div<Arg, U2>, U10>>::all()
+            .map(Bounded::value)
+            .eq(0..20));
+        assert_eq!(
+            (0, Some(256)),
+            Bounded::<u8, IsPowerOfTwo<Arg>>::all().size_hint()
+        );
+
+        let flipped = Bounded::<u8, Gt<U5, Arg>>::all();
+        assert_eq!(5, flipped.len());
+        assert!(flipped.map(Bounded::value).eq(0..5));
+    }
+
+    #[test]
+    fn all_len() {
+        let mut all = Bounded::<u8, Ge<Arg, U10>>::all().skip(1);
+        assert_eq!((245, Some(245)), all.size_hint());
+        assert_eq!(245, all.len());
+        assert_eq!(Some(11), all.next().map(Bounded::value));
+        assert_eq!(244, all.len());
+    }
+
+    #[test]
+    fn all_fused() {
+        let mut all = Bounded::<u8, Ne<Arg, U0>>::all().skip(254);
+        assert_eq!(Some(255), all.next().map(Bounded::value));
+        assert_eq!(None, all.next());
+        assert_eq!(None, all.next());
+    }
+}
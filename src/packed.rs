@@ -0,0 +1,306 @@
+//! Bounded values stored as an offset from the bound's minimum.
+
+use crate::{
+    expr::{AsBound, Contains, MinOf, NonEmpty, Span, SpanOf},
+    typenum::{Bit, IsLessOrEqual, LeEq, Len, Length, Unsigned, B0, B1, U16, U32, U8},
+    value::ToValue,
+    Bounded,
+};
+use core::{cmp::Ordering, fmt, hash::Hash, marker::PhantomData};
+
+/// Primitive integers which can be stored as an offset from a minimum.
+pub trait Offset: Copy {
+    /// Returns `self - min` as an unsigned value.
+    fn offset_from(self, min: Self) -> u64;
+    /// Returns `min + offset`.
+    fn from_offset(min: Self, offset: u64) -> Self;
+}
+
+macro_rules! impl_offset {
+    ( $Int:ty, $Unsigned:ty ) => {
+        impl Offset for $Int {
+            #[inline]
+            fn offset_from(self, min: Self) -> u64 {
+                self.wrapping_sub(min) as $Unsigned as u64
+            }
+
+            #[inline]
+            fn from_offset(min: Self, offset: u64) -> Self {
+                min.wrapping_add(offset as $Unsigned as $Int)
+            }
+        }
+    };
+}
+
+impl_offset!(i8, u8);
+impl_offset!(i16, u16);
+impl_offset!(i32, u32);
+impl_offset!(i64, u64);
+impl_offset!(isize, usize);
+impl_offset!(u8, u8);
+impl_offset!(u16, u16);
+impl_offset!(u32, u32);
+impl_offset!(u64, u64);
+impl_offset!(usize, usize);
+
+/// Unsigned integers used as the storage of `Packed`.
+pub trait Repr: Copy + Ord + Hash {
+    fn from_offset(offset: u64) -> Self;
+    fn into_offset(self) -> u64;
+}
+
+macro_rules! impl_repr {
+    ( $Repr:ty ) => {
+        impl Repr for $Repr {
+            #[inline]
+            fn from_offset(offset: u64) -> Self {
+                offset as Self
+            }
+
+            #[inline]
+            fn into_offset(self) -> u64 {
+                self as u64
+            }
+        }
+    };
+}
+
+impl_repr!(u8);
+impl_repr!(u16);
+impl_repr!(u32);
+impl_repr!(u64);
+
+/// Selects the narrowest `Repr` holding the given number of bits.
+pub trait Narrowest {
+    type Output: Repr;
+}
+
+impl<N> Narrowest for N
+where
+    N: IsLessOrEqual<U8> + IsLessOrEqual<U16> + IsLessOrEqual<U32>,
+    (LeEq<N, U8>, LeEq<N, U16>, LeEq<N, U32>): helper_traits::Select,
+{
+    type Output = <(LeEq<N, U8>, LeEq<N, U16>, LeEq<N, U32>) as helper_traits::Select>::Output;
+}
+
+mod helper_traits {
+    use super::*;
+
+    pub trait Select {
+        type Output: Repr;
+    }
+
+    impl<B16: Bit, B32: Bit> Select for (B1, B16, B32) {
+        type Output = u8;
+    }
+
+    impl<B32: Bit> Select for (B0, B1, B32) {
+        type Output = u16;
+    }
+
+    impl Select for (B0, B0, B1) {
+        type Output = u32;
+    }
+
+    impl Select for (B0, B0, B0) {
+        type Output = u64;
+    }
+}
+
+/// A bound whose values can be stored as an offset from its minimum.
+///
+/// Bounds whose `Interval` is empty are rejected.
+/// ```compile_fail
+/// use boundnum::{expr::*, typenum::consts::*, Bounded};
+///
+/// Bounded::<u8, BitAnd<Lt<Arg, U3>, Gt<Arg, U7>>>::all();
+/// ```
+pub trait Packable<T>: AsBound<T> {
+    /// The number of bits needed for `Max - Min` of the bound's `Interval`.
+    type Bits: Unsigned;
+    /// The narrowest unsigned integer holding `Bits` bits.
+    type Repr: Repr;
+    /// The minimum of the bound's `Interval`.
+    const MIN: T;
+    /// `Max - Min` of the bound's `Interval`.
+    const SPAN: u64;
+}
+
+impl<T, B> Packable<T> for B
+where
+    B: AsBound<T> + NonEmpty<T> + Span<T>,
+    MinOf<B, T>: ToValue<T>,
+    SpanOf<B, T>: Len,
+    Length<SpanOf<B, T>>: Unsigned + Narrowest,
+{
+    type Bits = Length<SpanOf<B, T>>;
+    type Repr = <Length<SpanOf<B, T>> as Narrowest>::Output;
+    const MIN: T = <MinOf<B, T> as ToValue<T>>::VALUE;
+    const SPAN: u64 = <SpanOf<B, T> as Unsigned>::U64;
+}
+
+/// A bounded number stored as an offset from the bound's minimum
+/// in the narrowest unsigned integer able to hold the bound's width.
+///
+/// ```
+/// use boundnum::{expr::*, typenum::{consts::*, Sum}, Bounded, Packed};
+///
+/// type Bound = RangeInclusive<U1000000, Sum<U1000000, U200>>;
+///
+/// let packed = Packed::from(Bounded::<u32, Bound>::new::<Sum<U1000000, U100>>());
+/// assert_eq!(1000100, packed.value());
+/// assert_eq!(1, core::mem::size_of::<Packed<u32, Bound>>());
+/// ```
+pub struct Packed<T, B: Packable<T>> {
+    offset: B::Repr,
+    bound: PhantomData<(T, B)>,
+}
+
+impl<T, B> Packed<T, B>
+where
+    T: Offset,
+    B: Packable<T>,
+{
+    pub fn new<A>() -> Self
+    where
+        A: ToValue<T>,
+        B: Contains<A, Output = crate::typenum::True>,
+    {
+        Bounded::<T, B>::new::<A>().into()
+    }
+
+    pub fn value(self) -> T {
+        T::from_offset(B::MIN, self.offset.into_offset())
+    }
+
+    pub fn bounded(self) -> Bounded<T, B> {
+        Bounded {
+            value: self.value(),
+            bound: PhantomData,
+        }
+    }
+}
+
+impl<T, B> From<Bounded<T, B>> for Packed<T, B>
+where
+    T: Offset,
+    B: Packable<T>,
+{
+    #[inline]
+    fn from(bounded: Bounded<T, B>) -> Self {
+        Packed {
+            offset: B::Repr::from_offset(bounded.value().offset_from(B::MIN)),
+            bound: PhantomData,
+        }
+    }
+}
+
+impl<T, B> From<Packed<T, B>> for Bounded<T, B>
+where
+    T: Offset,
+    B: Packable<T>,
+{
+    #[inline]
+    fn from(packed: Packed<T, B>) -> Self {
+        packed.bounded()
+    }
+}
+
+impl<T, B: Packable<T>> Clone for Packed<T, B> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, B: Packable<T>> Copy for Packed<T, B> {}
+
+impl<T, B: Packable<T>> PartialEq for Packed<T, B> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+    }
+}
+
+impl<T, B: Packable<T>> Eq for Packed<T, B> {}
+
+/// Offsets keep the order of the values.
+impl<T, B: Packable<T>> PartialOrd for Packed<T, B> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, B: Packable<T>> Ord for Packed<T, B> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.offset.cmp(&other.offset)
+    }
+}
+
+impl<T, B: Packable<T>> Hash for Packed<T, B> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.offset.hash(state)
+    }
+}
+
+impl<T, B> fmt::Debug for Packed<T, B>
+where
+    T: Offset + fmt::Debug,
+    B: Packable<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Packed").field(&self.value()).finish()
+    }
+}
+
+impl<T, B> fmt::Display for Packed<T, B>
+where
+    T: Offset + fmt::Display,
+    B: Packable<T>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expr::{Arg, Eq, Range, RangeFrom, RangeInclusive, Rem},
+        typenum::consts::*,
+        Boundable,
+    };
+    use core::mem::size_of;
+
+    #[test]
+    fn narrowest_repr() {
+        assert_eq!(1, size_of::<Packed<u32, RangeInclusive<U768, U1023>>>());
+        assert_eq!(2, size_of::<Packed<u32, RangeInclusive<U768, U1024>>>());
+        assert_eq!(1, size_of::<Packed<i64, Range<N100, P100>>>());
+        assert_eq!(2, size_of::<Packed<u16, RangeFrom<U1024>>>());
+        assert_eq!(4, size_of::<Packed<u64, Range<U0, U4294967296>>>());
+        assert_eq!(8, size_of::<Packed<i64, Eq<Rem<Arg, P2>, Z0>>>());
+    }
+
+    #[test]
+    fn roundtrip() {
+        for value in -100..100 {
+            let bounded: Bounded<i16, Range<N100, P100>> = value.bound().unwrap();
+            let packed = Packed::from(bounded);
+            assert_eq!(value, packed.value());
+            assert_eq!(bounded, packed.bounded());
+        }
+    }
+
+    #[test]
+    fn order() {
+        let low = Packed::<i8, RangeInclusive<N3, P3>>::new::<N3>();
+        let high = Packed::<i8, RangeInclusive<N3, P3>>::new::<P2>();
+        assert!(low < high);
+        assert_eq!(low, Packed::new::<N3>());
+    }
+}
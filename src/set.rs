@@ -0,0 +1,288 @@
+//! A bit set over the values of a `Bounded` type.
+
+use crate::{
+    map::Key,
+    typenum::{Quot, Sum, Unsigned, U63, U64},
+};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    ops::{Add, Div},
+};
+use generic_array::{ArrayLength, GenericArray};
+
+/// Computes the number of `u64` words holding `Size` bits.
+pub trait Words {
+    type Output: ArrayLength;
+}
+
+impl<Size> Words for Size
+where
+    Size: Add<U63>,
+    Sum<Size, U63>: Div<U64>,
+    Quot<Sum<Size, U63>, U64>: ArrayLength,
+{
+    type Output = Quot<Sum<Size, U63>, U64>;
+}
+
+/// Get a `Words`'s output type.
+pub type WordsOf<Size> = <Size as Words>::Output;
+
+/// A set with one bit for each index of the `Key` `K`, sized at compile time.
+///
+/// ```
+/// use boundnum::{expr::*, set::BoundedSet, typenum::consts::*, Bounded, Boundable};
+///
+/// type Channel = Bounded<u8, Range<U0, U100>>;
+///
+/// let low: BoundedSet<Channel> = (0..10).filter_map(Boundable::bound).collect();
+/// let even: BoundedSet<Channel> = (0..100).step_by(2).filter_map(Boundable::bound).collect();
+///
+/// let both = low.intersection(&even);
+/// assert_eq!(5, both.len());
+/// assert!(both.iter().map(Bounded::value).eq([0, 2, 4, 6, 8]));
+/// ```
+pub struct BoundedSet<K>
+where
+    K: Key,
+    K::Size: Words,
+{
+    words: GenericArray<u64, WordsOf<K::Size>>,
+}
+
+impl<K> BoundedSet<K>
+where
+    K: Key,
+    K::Size: Words,
+{
+    pub fn new() -> Self {
+        BoundedSet {
+            words: GenericArray::default(),
+        }
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn contains(&self, value: K) -> bool {
+        self.bit(value.index() as usize)
+    }
+
+    /// Returns whether the value was newly inserted.
+    pub fn insert(&mut self, value: K) -> bool {
+        let index = value.index() as usize;
+        let inserted = !self.bit(index);
+        self.words[index / 64] |= 1 << (index % 64);
+        inserted
+    }
+
+    /// Returns whether the value was present.
+    pub fn remove(&mut self, value: K) -> bool {
+        let index = value.index() as usize;
+        let removed = self.bit(index);
+        self.words[index / 64] &= !(1 << (index % 64));
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.words.iter_mut().for_each(|word| *word = 0);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |lhs, rhs| lhs | rhs)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |lhs, rhs| lhs & rhs)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |lhs, rhs| lhs & !rhs)
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |lhs, rhs| lhs ^ rhs)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .all(|(lhs, rhs)| lhs & !rhs == 0)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Iterates values in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = K> + FusedIterator + '_ {
+        (0..K::Size::USIZE)
+            .filter(move |&index| self.bit(index))
+            .filter_map(|index| K::from_index(index as u128))
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        let mut set = Self::new();
+        for ((word, lhs), rhs) in set
+            .words
+            .iter_mut()
+            .zip(self.words.iter())
+            .zip(other.words.iter())
+        {
+            *word = f(*lhs, *rhs);
+        }
+        set
+    }
+}
+
+impl<K> Default for BoundedSet<K>
+where
+    K: Key,
+    K::Size: Words,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K> Clone for BoundedSet<K>
+where
+    K: Key,
+    K::Size: Words,
+{
+    fn clone(&self) -> Self {
+        BoundedSet {
+            words: self.words.clone(),
+        }
+    }
+}
+
+impl<K> PartialEq for BoundedSet<K>
+where
+    K: Key,
+    K::Size: Words,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words
+    }
+}
+
+impl<K> Eq for BoundedSet<K>
+where
+    K: Key,
+    K::Size: Words,
+{
+}
+
+impl<K> Hash for BoundedSet<K>
+where
+    K: Key,
+    K::Size: Words,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.words.hash(state)
+    }
+}
+
+impl<K> fmt::Debug for BoundedSet<K>
+where
+    K: Key + fmt::Debug,
+    K::Size: Words,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K> core::iter::FromIterator<K> for BoundedSet<K>
+where
+    K: Key,
+    K::Size: Words,
+{
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K> Extend<K> for BoundedSet<K>
+where
+    K: Key,
+    K::Size: Words,
+{
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        expr::{Range, RangeInclusive},
+        typenum::consts::*,
+        Boundable, Bounded,
+    };
+
+    type Small = Bounded<i8, RangeInclusive<N3, P3>>;
+    type Wide = Bounded<u16, Range<U100, U300>>;
+
+    #[test]
+    fn words() {
+        assert_eq!(1, WordsOf::<<Small as Key>::Size>::USIZE);
+        assert_eq!(4, WordsOf::<<Wide as Key>::Size>::USIZE);
+        assert_eq!(8, core::mem::size_of::<BoundedSet<Small>>());
+        assert_eq!(32, core::mem::size_of::<BoundedSet<Wide>>());
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut set = BoundedSet::<Wide>::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Wide::new::<U299>()));
+        assert!(!set.insert(Wide::new::<U299>()));
+        assert!(set.insert(Wide::new::<U100>()));
+        assert_eq!(2, set.len());
+        assert!(set.contains(Wide::new::<U100>()));
+        assert!(set.remove(Wide::new::<U100>()));
+        assert!(!set.remove(Wide::new::<U100>()));
+        assert!(set.iter().map(Bounded::value).eq([299]));
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let set = |values: &[i8]| -> BoundedSet<Small> {
+            values.iter().map(|&value| value.bound().unwrap()).collect()
+        };
+        let lhs = set(&[-3, -1, 0, 2]);
+        let rhs = set(&[-1, 2, 3]);
+        assert_eq!(set(&[-3, -1, 0, 2, 3]), lhs.union(&rhs));
+        assert_eq!(set(&[-1, 2]), lhs.intersection(&rhs));
+        assert_eq!(set(&[-3, 0]), lhs.difference(&rhs));
+        assert_eq!(set(&[-3, 0, 3]), lhs.symmetric_difference(&rhs));
+        assert!(set(&[-1, 2]).is_subset(&lhs));
+        assert!(!rhs.is_subset(&lhs));
+        assert!(set(&[-3, 0]).is_disjoint(&rhs));
+        assert!(lhs.iter().rev().map(Bounded::value).eq([2, 0, -1, -3]));
+    }
+}
@@ -1,6 +1,9 @@
 //! Type to value conversions.
 
-use core::ops::Add;
+use core::{
+    convert::TryFrom,
+    ops::{Add, Sub},
+};
 use typenum::*;
 
 pub trait ToValue<I> {
@@ -40,6 +43,178 @@ where
     type Output = Add1<UInt<U, B>>;
 }
 
+/// Adds one to a `typenum` number, keeping its kind.
+pub trait Succ {
+    type Output;
+}
+
+/// Get a `Succ`'s output type.
+pub type SuccOf<T> = <T as Succ>::Output;
+
+impl Succ for UTerm {
+    type Output = U1;
+}
+
+impl<U: Unsigned, B: Bit> Succ for UInt<U, B>
+where
+    UInt<U, B>: Add<B1>,
+{
+    type Output = Add1<UInt<U, B>>;
+}
+
+impl Succ for Z0 {
+    type Output = P1;
+}
+
+impl<U: Unsigned + NonZero> Succ for PInt<U>
+where
+    PInt<U>: Add<P1>,
+{
+    type Output = Sum<PInt<U>, P1>;
+}
+
+impl<U: Unsigned + NonZero> Succ for NInt<U>
+where
+    NInt<U>: Add<P1>,
+{
+    type Output = Sum<NInt<U>, P1>;
+}
+
+/// Subtracts one from a `typenum` number, keeping its kind.
+///
+/// `UTerm` has no predecessor.
+pub trait Pred {
+    type Output;
+}
+
+/// Get a `Pred`'s output type.
+pub type PredOf<T> = <T as Pred>::Output;
+
+impl<U: Unsigned, B: Bit> Pred for UInt<U, B>
+where
+    UInt<U, B>: Sub<B1>,
+{
+    type Output = Sub1<UInt<U, B>>;
+}
+
+impl Pred for Z0 {
+    type Output = N1;
+}
+
+impl<U: Unsigned + NonZero> Pred for PInt<U>
+where
+    PInt<U>: Sub<P1>,
+{
+    type Output = Diff<PInt<U>, P1>;
+}
+
+impl<U: Unsigned + NonZero> Pred for NInt<U>
+where
+    NInt<U>: Sub<P1>,
+{
+    type Output = Diff<NInt<U>, P1>;
+}
+
+/// Converts a non-negative `typenum` number to `typenum::Unsigned`.
+pub trait ToUnsigned {
+    type Output: Unsigned;
+}
+
+/// Get a `ToUnsigned`'s output type.
+pub type UnsignedOf<T> = <T as ToUnsigned>::Output;
+
+impl ToUnsigned for UTerm {
+    type Output = UTerm;
+}
+
+impl<U: Unsigned, B: Bit> ToUnsigned for UInt<U, B> {
+    type Output = UInt<U, B>;
+}
+
+impl ToUnsigned for Z0 {
+    type Output = U0;
+}
+
+impl<U: Unsigned + NonZero> ToUnsigned for PInt<U> {
+    type Output = U;
+}
+
+/// Converts a `typenum` constant to `typenum::Integer`,
+/// so that unsigned and signed constants compare with each other.
+///
+/// Orderings convert in the order of `core::cmp::Ordering`.
+pub trait ToInteger {
+    type Output;
+}
+
+/// Get a `ToInteger`'s output type.
+pub type IntegerOf<T> = <T as ToInteger>::Output;
+
+impl ToInteger for UTerm {
+    type Output = Z0;
+}
+
+impl<U: Unsigned, B: Bit> ToInteger for UInt<U, B> {
+    type Output = PInt<UInt<U, B>>;
+}
+
+impl ToInteger for Z0 {
+    type Output = Z0;
+}
+
+impl<U: Unsigned + NonZero> ToInteger for PInt<U> {
+    type Output = PInt<U>;
+}
+
+impl<U: Unsigned + NonZero> ToInteger for NInt<U> {
+    type Output = NInt<U>;
+}
+
+impl ToInteger for B0 {
+    type Output = Z0;
+}
+
+impl ToInteger for B1 {
+    type Output = P1;
+}
+
+impl ToInteger for Less {
+    type Output = N1;
+}
+
+impl ToInteger for Equal {
+    type Output = Z0;
+}
+
+impl ToInteger for Greater {
+    type Output = P1;
+}
+
+/// Converts a `typenum` number of any kind to `i128`, failing to compile beyond its range.
+pub trait ToI128 {
+    const I128: i128;
+}
+
+impl ToI128 for UTerm {
+    const I128: i128 = 0;
+}
+
+impl<U: Unsigned + ToI128, B: Bit> ToI128 for UInt<U, B> {
+    const I128: i128 = U::I128 * 2 + B::U8 as i128;
+}
+
+impl ToI128 for Z0 {
+    const I128: i128 = 0;
+}
+
+impl<U: Unsigned + NonZero + ToI128> ToI128 for PInt<U> {
+    const I128: i128 = U::I128;
+}
+
+impl<U: Unsigned + NonZero + ToI128> ToI128 for NInt<U> {
+    const I128: i128 = -U::I128;
+}
+
 impl TypeBound for i8 {
     type Min = NInt<Exp<U2, U7>>;
     type Max = PInt<Sub1<Exp<U2, U7>>>;
@@ -101,6 +276,19 @@ macro_rules! impl_tovalue_for_integer {
         {
             const VALUE: $ToType = Self::$Const;
         }
+
+        impl ToValue<$ToType> for UTerm {
+            const VALUE: $ToType = 0;
+        }
+
+        impl<U, B> ToValue<$ToType> for UInt<U, B>
+        where
+            U: Unsigned,
+            B: Bit,
+            PInt<UInt<U, B>>: ToValue<$ToType>,
+        {
+            const VALUE: $ToType = <PInt<UInt<U, B>> as ToValue<$ToType>>::VALUE;
+        }
     };
 }
 
@@ -162,6 +350,17 @@ macro_rules! impl_tovalue_for_unsigned {
         {
             const VALUE: $ToType = Self::$Const;
         }
+
+        impl ToValue<$ToType> for Z0 {
+            const VALUE: $ToType = 0;
+        }
+
+        impl<U> ToValue<$ToType> for PInt<U>
+        where
+            U: Unsigned + NonZero + ToValue<$ToType>,
+        {
+            const VALUE: $ToType = U::VALUE;
+        }
     };
 }
 
@@ -186,6 +385,559 @@ macro_rules! impl_tovalue_for_bits {
 impl_tovalue_for_bits!(u8, U8);
 impl_tovalue_for_bits!(bool, BOOL);
 
+macro_rules! impl_tovalue_for_ordering {
+    ( $($name:ident),* ) => {
+        $(
+            impl ToValue<core::cmp::Ordering> for $name {
+                const VALUE: core::cmp::Ordering = core::cmp::Ordering::$name;
+            }
+        )*
+    };
+}
+
+impl_tovalue_for_ordering!(Less, Equal, Greater);
+
+impl<X, Y, L, R> ToValue<(X, Y)> for (L, R)
+where
+    L: ToValue<X>,
+    R: ToValue<Y>,
+{
+    const VALUE: (X, Y) = (L::VALUE, R::VALUE);
+}
+
+/// An integer evaluated exactly rather than as the argument's type:
+/// a constant out of its range, or the result of arithmetic.
+///
+/// A result beyond `i128` saturates to an infinity of its sign, which orders exactly
+/// against every finite value, so that comparing it with a constant agrees with `typenum`.
+/// Infinities of the same sign compare equal, and arithmetic on infinities is approximate:
+/// `∞ - ∞` is zero, and the remainder of an infinity is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Wide {
+    /// A value below `i128::MIN`.
+    NegInf,
+    Finite(i128),
+    /// A value above `i128::MAX`.
+    PosInf,
+}
+
+use Wide::{Finite, NegInf, PosInf};
+
+impl Wide {
+    /// Returns the value, or `None` if it is infinite.
+    pub const fn get(self) -> Option<i128> {
+        match self {
+            Finite(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `-1`, `0` or `1` by the sign of the value.
+    pub const fn signum(self) -> i128 {
+        match self {
+            NegInf => -1,
+            Finite(value) => value.signum(),
+            PosInf => 1,
+        }
+    }
+
+    /// `-1` for `NegInf`, `1` for `PosInf` and `0` for finite values.
+    const fn infinite_sign(self) -> i128 {
+        match self {
+            NegInf => -1,
+            Finite(_) => 0,
+            PosInf => 1,
+        }
+    }
+
+    /// The sign of the product of `self` and `rhs`.
+    const fn sign_product(self, rhs: Wide) -> i128 {
+        self.signum() * rhs.signum()
+    }
+
+    /// The infinity of the sign of `sign`, or zero.
+    const fn infinity(sign: i128) -> Wide {
+        match sign.signum() {
+            -1 => NegInf,
+            0 => Finite(0),
+            _ => PosInf,
+        }
+    }
+
+    /// A checked result, saturating to the infinity of the sign of `sign` on overflow.
+    fn saturate(value: Option<i128>, sign: i128) -> Wide {
+        value.map_or(Wide::infinity(sign), Finite)
+    }
+
+    /// `div_euclid` of primitive integers.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    pub fn div_euclid(self, rhs: Wide) -> Wide {
+        match (self.nonzero_divisor(rhs), rhs) {
+            (Finite(l), Finite(r)) => Wide::saturate(l.checked_div_euclid(r), 1),
+            (Finite(l), _) => Finite(if l < 0 { -rhs.signum() } else { 0 }),
+            (_, _) => Wide::infinity(self.sign_product(rhs)),
+        }
+    }
+
+    /// `rem_euclid` of primitive integers.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    pub fn rem_euclid(self, rhs: Wide) -> Wide {
+        match (self.nonzero_divisor(rhs), rhs) {
+            (Finite(l), Finite(r)) => Finite(l.wrapping_rem_euclid(r)),
+            (Finite(l), _) if l < 0 => PosInf,
+            (Finite(l), _) => Finite(l),
+            (_, _) => Finite(0),
+        }
+    }
+
+    /// Panics like primitive integers on a zero divisor.
+    fn nonzero_divisor(self, rhs: Wide) -> Wide {
+        assert!(rhs != Finite(0), "attempt to divide by zero");
+        self
+    }
+
+    /// Saturates `i128::MIN` and `MAX` to infinities.
+    fn from_saturated(value: i128) -> Wide {
+        match value {
+            i128::MIN => NegInf,
+            i128::MAX => PosInf,
+            value => Finite(value),
+        }
+    }
+
+    /// Approximates infinities as `i128::MIN` and `MAX`.
+    fn to_saturated(self) -> i128 {
+        match self {
+            NegInf => i128::MIN,
+            Finite(value) => value,
+            PosInf => i128::MAX,
+        }
+    }
+}
+
+impl core::ops::Neg for Wide {
+    type Output = Wide;
+    fn neg(self) -> Wide {
+        match self {
+            NegInf => PosInf,
+            Finite(value) => Wide::saturate(value.checked_neg(), 1),
+            PosInf => NegInf,
+        }
+    }
+}
+
+impl core::ops::Add for Wide {
+    type Output = Wide;
+    fn add(self, rhs: Wide) -> Wide {
+        match (self, rhs) {
+            (Finite(l), Finite(r)) => Wide::saturate(l.checked_add(r), l.signum()),
+            _ => Wide::infinity(self.infinite_sign() + rhs.infinite_sign()),
+        }
+    }
+}
+
+impl core::ops::Sub for Wide {
+    type Output = Wide;
+    fn sub(self, rhs: Wide) -> Wide {
+        match (self, rhs) {
+            (Finite(l), Finite(r)) => Wide::saturate(l.checked_sub(r), if l > r { 1 } else { -1 }),
+            _ => self + -rhs,
+        }
+    }
+}
+
+impl core::ops::Mul for Wide {
+    type Output = Wide;
+    fn mul(self, rhs: Wide) -> Wide {
+        let sign = self.sign_product(rhs);
+        match (self, rhs) {
+            (Finite(l), Finite(r)) => Wide::saturate(l.checked_mul(r), sign),
+            _ => Wide::infinity(sign),
+        }
+    }
+}
+
+/// Truncates toward zero like primitive integers.
+impl core::ops::Div for Wide {
+    type Output = Wide;
+    fn div(self, rhs: Wide) -> Wide {
+        match (self.nonzero_divisor(rhs), rhs) {
+            (Finite(l), Finite(r)) => Wide::saturate(l.checked_div(r), 1),
+            (Finite(_), _) => Finite(0),
+            (_, _) => Wide::infinity(self.sign_product(rhs)),
+        }
+    }
+}
+
+impl core::ops::Rem for Wide {
+    type Output = Wide;
+    fn rem(self, rhs: Wide) -> Wide {
+        match (self.nonzero_divisor(rhs), rhs) {
+            (Finite(l), Finite(r)) => Finite(l.wrapping_rem(r)),
+            (Finite(l), _) => Finite(l),
+            (_, _) => Finite(0),
+        }
+    }
+}
+
+/// Shifts without losing bits, saturating to infinities.
+///
+/// # Panics
+/// Panics if `rhs` is negative.
+impl core::ops::Shl for Wide {
+    type Output = Wide;
+    fn shl(self, rhs: Wide) -> Wide {
+        assert!(rhs >= Finite(0), "attempt to shift by a negative amount");
+        match (self, rhs) {
+            (Finite(0), _) => Finite(0),
+            (Finite(l), Finite(r)) if r < 128 => {
+                let shifted = l << r;
+                if shifted >> r == l {
+                    Finite(shifted)
+                } else {
+                    Wide::infinity(l.signum())
+                }
+            }
+            _ => Wide::infinity(self.signum()),
+        }
+    }
+}
+
+/// Rounds toward negative infinity like primitive integers, but for any amount.
+///
+/// # Panics
+/// Panics if `rhs` is negative.
+impl core::ops::Shr for Wide {
+    type Output = Wide;
+    fn shr(self, rhs: Wide) -> Wide {
+        assert!(rhs >= Finite(0), "attempt to shift by a negative amount");
+        match (self, rhs) {
+            (Finite(l), Finite(r)) => Finite(l >> r.min(127)),
+            (Finite(l), _) => Finite(l >> 127),
+            (_, _) => self,
+        }
+    }
+}
+
+impl core::ops::Not for Wide {
+    type Output = Wide;
+    fn not(self) -> Wide {
+        match self {
+            NegInf => PosInf,
+            Finite(value) => Finite(!value),
+            PosInf => NegInf,
+        }
+    }
+}
+
+/// Approximates infinities as `i128::MIN` and `MAX`.
+macro_rules! impl_bit_op_for_wide {
+    ( $Op:ident, $op:ident, $($Int:ty),* ) => {
+        impl core::ops::$Op for Wide {
+            type Output = Wide;
+            fn $op(self, rhs: Wide) -> Wide {
+                match (self, rhs) {
+                    (Finite(l), Finite(r)) => Finite(core::ops::$Op::$op(l, r)),
+                    _ => Wide::from_saturated(core::ops::$Op::$op(
+                        self.to_saturated(),
+                        rhs.to_saturated(),
+                    )),
+                }
+            }
+        }
+
+        $(
+            impl core::ops::$Op<$Int> for Wide {
+                type Output = Wide;
+                fn $op(self, rhs: $Int) -> Wide {
+                    core::ops::$Op::$op(self, Wide::from(rhs))
+                }
+            }
+
+            impl core::ops::$Op<Wide> for $Int {
+                type Output = Wide;
+                fn $op(self, rhs: Wide) -> Wide {
+                    core::ops::$Op::$op(Wide::from(self), rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_op_for_wide!(BitAnd, bitand, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_bit_op_for_wide!(BitOr, bitor, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_bit_op_for_wide!(BitXor, bitxor, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_from_for_wide {
+    ( $($Int:ty),* ) => {
+        $(
+            impl From<$Int> for Wide {
+                fn from(value: $Int) -> Wide {
+                    Finite(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_wide!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+/// Saturates values above `i128::MAX` to `PosInf`.
+impl From<u128> for Wide {
+    fn from(value: u128) -> Wide {
+        i128::try_from(value).map_or(PosInf, Finite)
+    }
+}
+
+/// Evaluates a typenum constant of any sign as `T`,
+/// or exactly as `Wide` if it is out of range.
+pub trait ToConst<T> {
+    type Output;
+    const CONST: Self::Output;
+}
+
+/// Constants in a bound on pairs evaluate as the type of both elements.
+impl<T, C: ToConst<T>> ToConst<(T, T)> for C {
+    type Output = C::Output;
+    const CONST: Self::Output = C::CONST;
+}
+
+/// Constants in a bound on strings evaluate as `usize`, the type of their lengths.
+impl<C: ToConst<usize>> ToConst<&str> for C {
+    type Output = C::Output;
+    const CONST: Self::Output = C::CONST;
+}
+
+/// Constants in a bound on slices evaluate as `usize`, the type of their lengths.
+impl<T, C: ToConst<usize>> ToConst<&[T]> for C {
+    type Output = C::Output;
+    const CONST: Self::Output = C::CONST;
+}
+
+mod helper_traits {
+    use super::*;
+
+    /// Evaluates `C` by the result of comparing it to the value just above `T`'s maximum.
+    pub trait CheckMax<T, C> {
+        type Output;
+        const CONST: Self::Output;
+    }
+
+    impl<T, C: ToValue<T>> CheckMax<T, C> for Less {
+        type Output = T;
+        const CONST: T = C::VALUE;
+    }
+
+    impl<T, C: ToI128> CheckMax<T, C> for Equal {
+        type Output = Wide;
+        const CONST: Wide = Wide::Finite(C::I128);
+    }
+
+    impl<T, C: ToI128> CheckMax<T, C> for Greater {
+        type Output = Wide;
+        const CONST: Wide = Wide::Finite(C::I128);
+    }
+
+    /// Evaluates `C` by the result of comparing it to the value just below `T`'s minimum.
+    pub trait CheckMin<T, C> {
+        type Output;
+        const CONST: Self::Output;
+    }
+
+    impl<T, C: ToValue<T>> CheckMin<T, C> for Greater {
+        type Output = T;
+        const CONST: T = C::VALUE;
+    }
+
+    impl<T, C: ToI128> CheckMin<T, C> for Equal {
+        type Output = Wide;
+        const CONST: Wide = Wide::Finite(C::I128);
+    }
+
+    impl<T, C: ToI128> CheckMin<T, C> for Less {
+        type Output = Wide;
+        const CONST: Wide = Wide::Finite(C::I128);
+    }
+
+    /// Evaluates `Self` by comparing it to `Outer`, the value just above `T`'s maximum.
+    pub trait ConstBelow<T, Outer> {
+        type Output;
+        const CONST: Self::Output;
+    }
+
+    impl<T, C, Outer> ConstBelow<T, Outer> for C
+    where
+        C: Cmp<Outer>,
+        Compare<C, Outer>: CheckMax<T, C>,
+    {
+        type Output = <Compare<C, Outer> as CheckMax<T, C>>::Output;
+        const CONST: Self::Output = <Compare<C, Outer> as CheckMax<T, C>>::CONST;
+    }
+
+    /// Evaluates `Self` by comparing it to `Outer`, the value just below `T`'s minimum.
+    pub trait ConstAbove<T, Outer> {
+        type Output;
+        const CONST: Self::Output;
+    }
+
+    impl<T, C, Outer> ConstAbove<T, Outer> for C
+    where
+        C: Cmp<Outer>,
+        Compare<C, Outer>: CheckMin<T, C>,
+    {
+        type Output = <Compare<C, Outer> as CheckMin<T, C>>::Output;
+        const CONST: Self::Output = <Compare<C, Outer> as CheckMin<T, C>>::CONST;
+    }
+}
+
+macro_rules! impl_toconst_for_integer {
+    ( $ToType:ty ) => {
+        impl ToConst<$ToType> for Z0 {
+            type Output = $ToType;
+            const CONST: $ToType = <Self as ToValue<$ToType>>::VALUE;
+        }
+
+        impl<U> ToConst<$ToType> for NInt<U>
+        where
+            U: Unsigned + NonZero,
+            NInt<U>: helper_traits::ConstAbove<$ToType, OuterOf<<$ToType as TypeBound>::Min>>,
+        {
+            type Output = <Self as helper_traits::ConstAbove<
+                $ToType,
+                OuterOf<<$ToType as TypeBound>::Min>,
+            >>::Output;
+            const CONST: Self::Output = <Self as helper_traits::ConstAbove<
+                $ToType,
+                OuterOf<<$ToType as TypeBound>::Min>,
+            >>::CONST;
+        }
+
+        impl<U> ToConst<$ToType> for PInt<U>
+        where
+            U: Unsigned + NonZero,
+            PInt<U>: helper_traits::ConstBelow<$ToType, OuterOf<<$ToType as TypeBound>::Max>>,
+        {
+            type Output = <Self as helper_traits::ConstBelow<
+                $ToType,
+                OuterOf<<$ToType as TypeBound>::Max>,
+            >>::Output;
+            const CONST: Self::Output = <Self as helper_traits::ConstBelow<
+                $ToType,
+                OuterOf<<$ToType as TypeBound>::Max>,
+            >>::CONST;
+        }
+
+        impl ToConst<$ToType> for UTerm {
+            type Output = $ToType;
+            const CONST: $ToType = 0;
+        }
+
+        impl<U, B> ToConst<$ToType> for UInt<U, B>
+        where
+            U: Unsigned,
+            B: Bit,
+            PInt<UInt<U, B>>: ToConst<$ToType>,
+        {
+            type Output = <PInt<UInt<U, B>> as ToConst<$ToType>>::Output;
+            const CONST: Self::Output = <PInt<UInt<U, B>> as ToConst<$ToType>>::CONST;
+        }
+    };
+}
+
+impl_toconst_for_integer!(i8);
+impl_toconst_for_integer!(i16);
+impl_toconst_for_integer!(i32);
+impl_toconst_for_integer!(i64);
+impl_toconst_for_integer!(isize);
+
+macro_rules! impl_toconst_for_unsigned {
+    ( $ToType:ty ) => {
+        impl ToConst<$ToType> for UTerm {
+            type Output = $ToType;
+            const CONST: $ToType = <Self as ToValue<$ToType>>::VALUE;
+        }
+
+        impl<U, B> ToConst<$ToType> for UInt<U, B>
+        where
+            U: Unsigned,
+            B: Bit,
+            UInt<U, B>: helper_traits::ConstBelow<$ToType, OuterOf<<$ToType as TypeBound>::Max>>,
+        {
+            type Output = <Self as helper_traits::ConstBelow<
+                $ToType,
+                OuterOf<<$ToType as TypeBound>::Max>,
+            >>::Output;
+            const CONST: Self::Output = <Self as helper_traits::ConstBelow<
+                $ToType,
+                OuterOf<<$ToType as TypeBound>::Max>,
+            >>::CONST;
+        }
+
+        impl ToConst<$ToType> for Z0 {
+            type Output = $ToType;
+            const CONST: $ToType = 0;
+        }
+
+        impl<U> ToConst<$ToType> for PInt<U>
+        where
+            U: Unsigned + NonZero + ToConst<$ToType>,
+        {
+            type Output = U::Output;
+            const CONST: Self::Output = U::CONST;
+        }
+
+        impl<U: Unsigned + NonZero> ToConst<$ToType> for NInt<U>
+        where
+            NInt<U>: ToI128,
+        {
+            type Output = Wide;
+            const CONST: Wide = Wide::Finite(<Self as ToI128>::I128);
+        }
+    };
+}
+
+impl_toconst_for_unsigned!(u8);
+impl_toconst_for_unsigned!(u16);
+impl_toconst_for_unsigned!(u32);
+impl_toconst_for_unsigned!(u64);
+impl_toconst_for_unsigned!(usize);
+
+/// Constants evaluate exactly as `i128` itself, up to its range.
+impl<C: ToI128> ToConst<i128> for C {
+    type Output = i128;
+    const CONST: i128 = C::I128;
+}
+
+/// Constants evaluate as `u128` up to `i128::MAX`, and negative ones exactly as `Wide`.
+impl ToConst<u128> for UTerm {
+    type Output = u128;
+    const CONST: u128 = 0;
+}
+
+impl<U: Unsigned + ToI128, B: Bit> ToConst<u128> for UInt<U, B> {
+    type Output = u128;
+    const CONST: u128 = <Self as ToI128>::I128 as u128;
+}
+
+impl ToConst<u128> for Z0 {
+    type Output = u128;
+    const CONST: u128 = 0;
+}
+
+impl<U: Unsigned + NonZero + ToI128> ToConst<u128> for PInt<U> {
+    type Output = u128;
+    const CONST: u128 = U::I128 as u128;
+}
+
+impl<U: Unsigned + NonZero + ToI128> ToConst<u128> for NInt<U> {
+    type Output = Wide;
+    const CONST: Wide = Wide::Finite(<Self as ToI128>::I128);
+}
+
 #[cfg(test)]
 mod tests {
     macro_rules! min_bound_tests {
@@ -246,6 +998,13 @@ mod tests {
         min_bound_tests!(i8);
         max_bound_tests!(i8);
         zero_test!(i8);
+
+        #[test]
+        fn out_of_range() {
+            assert_eq!(Some(-129), <N129 as ToConst<i8>>::CONST.get());
+            assert_eq!(-128, <N128 as ToConst<i8>>::CONST);
+            assert_eq!(Some(128), <P128 as ToConst<i8>>::CONST.get());
+        }
     }
 
     mod i16 {
@@ -280,6 +1039,13 @@ mod tests {
         use super::super::*;
         max_bound_tests!(u8);
         bit_tests!(u8);
+
+        #[test]
+        fn out_of_range() {
+            assert_eq!(255, <U255 as ToConst<u8>>::CONST);
+            assert_eq!(Some(256), <U256 as ToConst<u8>>::CONST.get());
+            assert_eq!(Some(-1), <N1 as ToConst<u8>>::CONST.get());
+        }
     }
 
     mod u16 {
@@ -287,6 +1053,28 @@ mod tests {
         max_bound_tests!(u16);
     }
 
+    mod wide {
+        use super::super::*;
+
+        #[test]
+        fn overflow_saturates() {
+            let overflow = Wide::from(i128::MAX) + Wide::from(1u8);
+            assert_eq!(Wide::PosInf, overflow);
+            assert_eq!(None, overflow.get());
+            assert!(overflow > Wide::from(u64::MAX));
+            assert_eq!(Wide::NegInf, -overflow);
+            assert_eq!(Wide::PosInf, -Wide::from(i128::MIN));
+            assert_eq!(Wide::NegInf, Wide::from(i128::MIN) * Wide::from(2u8));
+            assert_eq!(Wide::from(0u8), Wide::PosInf * Wide::from(0u8));
+            assert_eq!(Wide::from(0u8), Wide::from(5u8) / Wide::PosInf);
+            assert_eq!(Wide::from(-1i8), Wide::from(-5i8).div_euclid(Wide::PosInf));
+            assert_eq!(Wide::PosInf, Wide::from(1u8) << Wide::from(127u8));
+            assert_eq!(Wide::from(-1i8), Wide::from(-5i8) >> Wide::from(200u8));
+            assert_eq!(Some(-1), (Wide::from(-2i8) | 1u8).get());
+            assert_eq!(Some(-6), (!Wide::from(5u8)).get());
+        }
+    }
+
     mod u32 {
         use super::super::*;
         max_bound_tests!(u32);